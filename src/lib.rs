@@ -29,6 +29,316 @@
 //!
 //!   Remove a static string from the internal HashSet. Return `true` if was present.
 //!
+//! - `staticize_repeat_join(s: &str, n: usize, sep: &str) -> &'static str`
+//!
+//!   Repeat a string `n` times with a separator between repetitions and
+//!   return a static string, e.g., `"?, ?, ?"` for SQL placeholders.
+//!
+//! - `staticize_rs_delimited(data: &[u8]) -> Result<Vec<&'static str>, Utf8Error>`
+//!
+//!   Split a record-separator (ASCII `0x1E`) delimited byte array and
+//!   staticize each field.
+//!
+//! - `staticize_fixed_width(s: &str, width: usize) -> &'static str`
+//!
+//!   Truncate (at a char boundary) or right-pad with spaces a string to
+//!   exactly `width` bytes and return a static string.
+//!
+//! - `all_staticized_between_lengths(min: usize, max: usize) -> Vec<&'static str>`
+//!
+//!   Return all static strings whose length is between `min` and `max`
+//!   (inclusive).
+//!
+//! - `staticize_table(headers: &[&str], rows: &[Vec<&str>]) -> &'static str`
+//!
+//!   Format a column-aligned ASCII table from headers and rows and return
+//!   a static string.
+//!
+//! - `staticize_kv_pairs(iter: impl Iterator<Item = (&str, &str)>, pair_sep: char, kv_sep: char) -> &'static str`
+//!
+//!   Build a `"k1=v1;k2=v2"` style string from key-value pairs and return
+//!   a static string.
+//!
+//! - `is_subset_interned(strings: &[&str]) -> bool`
+//!
+//!   Check if all the given strings have been staticized before, acquiring
+//!   the lock only once.
+//!
+//! - `staticize_deduplicated(strings: &[&str]) -> Vec<&'static str>`
+//!
+//!   Staticize each unique string once and return them in first-seen order.
+//!
+//! - `staticize_sorted(strings: &[&str]) -> Vec<&'static str>`
+//!
+//!   Sort the strings lexicographically, staticize each and return them in
+//!   sorted order.
+//!
+//! - `staticize_with_char_count(s: &str) -> (&'static str, usize)`
+//!
+//!   Staticize a string and return it together with its character count.
+//!
+//! - `destaticize_set(to_remove: &HashSet<&str>) -> usize`
+//!
+//!   Remove a set of static strings at once. Return the number of strings
+//!   that were present.
+//!
+//! - `staticize_chunks(s: &str, chunk_size: usize) -> Vec<&'static str>`
+//!
+//!   Split a string into chunks of at most `chunk_size` bytes (respecting
+//!   UTF-8 char boundaries) and staticize each chunk.
+//!
+//! - `staticize_binary_size(bits: u64) -> &'static str`
+//!
+//!   Format a bit count as a binary size string (`Kib`, `Mib`, `Gib`, `Tib`)
+//!   and return a static string.
+//!
+//! - `staticize_roman(n: u32) -> Option<&'static str>`
+//!
+//!   Convert a number in `1..=3999` to its Roman numeral representation and
+//!   return a static string.
+//!
+//! - `staticize_ordinal(n: u64) -> &'static str`
+//!
+//!   Format a number as an English ordinal, e.g., `"1st"`, `"2nd"`, `"3rd"`,
+//!   and return a static string.
+//!
+//! - `staticize_bincode(bytes: &[u8]) -> Result<&'static str, bincode::Error>`
+//!
+//!   Deserialize a `bincode`-encoded string and return a static string.
+//!   Requires the `bincode` feature.
+//!
+//! - `staticize_span_name(name: &str, target: &str, level: tracing::Level) -> &'static tracing::Metadata<'static>`
+//!
+//!   Staticize a span name, register it as a `tracing` span callsite and
+//!   return its metadata for `tracing::Span::new()`. Requires the `tracing`
+//!   feature.
+//!
+//! - `staticize_word_wrap(s: &str, width: usize) -> Vec<&'static str>`
+//!
+//!   Word-wrap a string at `width` characters and staticize each line.
+//!
+//! - `all_staticized_ascii() -> Vec<&'static str>`
+//!
+//!   Return all static strings that consist of ASCII characters only.
+//!
+//! - `all_staticized_non_ascii() -> Vec<&'static str>`
+//!
+//!   Return all static strings that contain non-ASCII characters.
+//!
+//! - `staticize_proto_string(bytes: &[u8]) -> Result<&'static str, prost::DecodeError>`
+//!
+//!   Decode a protobuf message with a `string` in field 1 from raw bytes and
+//!   return a static string. Requires the `prost` feature.
+//!
+//! - `staticize_markdown_escaped(s: &str) -> &'static str`
+//!
+//!   Escape Markdown special characters and return a static string.
+//!   Requires the `markdown` feature.
+//!
+//! - `staticize_with_fallback(s: &str, fallback: impl FnOnce() -> String) -> &'static str`
+//!
+//!   Staticize a string, or the string computed by `fallback()` if it is
+//!   empty.
+//!
+//! - `evict_prefixes(prefixes: &[&str]) -> HashMap<&str, usize>`
+//!
+//!   Remove all static strings starting with any of the given prefixes.
+//!   Return the number of removed strings per prefix.
+//!
+//! - `staticize_utf8(bytes: &[u8]) -> Result<&'static str, Utf8Error>`
+//!
+//!   Convert UTF-8 bytes to a static string.
+//!
+//! - `staticize_hash<H: Hash>(val: &H) -> &'static str`
+//!
+//!   Hash a value and return the hash as a static hex string, e.g.,
+//!   `"0x00FF12AB34CD56EF"`.
+//!
+//! - `staticize_conditional(cond: bool, t: &str, f: &str) -> &'static str`
+//!
+//!   Staticize `t` if `cond` is true, `f` otherwise.
+//!
+//! - `intern_map_entry<K>(map: &mut HashMap<K, String>, key: K, default: impl FnOnce() -> String) -> &'static str`
+//!
+//!   Look up `key` in `map`, inserting `default()` if absent, and return the
+//!   value as a static string.
+//!
+//! - `staticize_kv(key: &str, value: &str) -> &'static str`
+//!
+//!   Format a `key=value` pair and return a static string.
+//!
+//! - `staticize_json_string_literal(s: &str) -> &'static str`
+//!
+//!   Quote and escape a string as a JSON string literal and return a static
+//!   string.
+//!
+//! - `staticize_from_reader_line_by_line<R: BufRead>(r: R, max_lines: usize) -> io::Result<Vec<&'static str>>`
+//!
+//!   Read up to `max_lines` lines from a reader and staticize each non-empty
+//!   line.
+//!
+//! - `staticize_split_once(s: &str, pat: char) -> Option<(&'static str, &'static str)>`
+//!
+//!   Split a string at the first occurrence of `pat` and staticize both
+//!   halves.
+//!
+//! - `staticize_prefixed_new(prefix: &'static str, s: &str) -> (&'static str, bool)`
+//!
+//!   Staticize a prefixed string, and report whether it had not been
+//!   staticized before.
+//!
+//! - `with_staticized<F, R>(f: F) -> R where F: FnOnce(&HashSet<&'static str>) -> R`
+//!
+//!   Call `f` with the internal HashSet while holding the lock.
+//!
+//! - `staticize_duration_secs(secs: f64) -> &'static str`
+//!
+//!   Format a number of seconds as a duration string, e.g., `"1h 2m 3.4s"`,
+//!   and return a static string.
+//!
+//! - `staticize_percentage(n: f64, d: f64) -> &'static str`
+//!
+//!   Format `n / d` as a percentage with two decimal places and return a
+//!   static string.
+//!
+//! - `intern_event_log(n: usize) -> Vec<InternEvent>`
+//!
+//!   Return the `n` most recent new, hit and evicted events of the internal
+//!   HashSet. Requires the `audit` feature.
+//!
+//! - `staticize_startup(s: &str) -> &'static str`
+//!
+//!   Same as `staticize()`, but the miss path is marked as `#[cold]`, for
+//!   code that runs once at startup.
+//!
+//! - `staticize_env_var_split(name: &str, sep: char) -> Option<Vec<&'static str>>`
+//!
+//!   Read an environment variable containing a separated list and staticize
+//!   each component.
+//!
+//! - `staticize_hex_dump(bytes: &[u8]) -> &'static str`
+//!
+//!   Format bytes as space-separated hex pairs, e.g., `"DE AD BE EF"`, and
+//!   return a static string.
+//!
+//! - `staticize_mac_addr(bytes: &[u8; 6]) -> &'static str`
+//!
+//!   Format a MAC address as `"AA:BB:CC:DD:EE:FF"` and return a static
+//!   string.
+//!
+//! - `intern_short_type_name<T: ?Sized>() -> &'static str`
+//!
+//!   Return the name of a type without module paths, e.g., `"Vec<String>"`,
+//!   as a static string.
+//!
+//! - `staticize_display_and_debug<T: Display + Debug>(val: &T) -> (&'static str, &'static str)`
+//!
+//!   Format a value with both `Display` and `Debug` and return the two
+//!   static strings.
+//!
+//! - `intern_array<const N: usize>(arr: [&str; N]) -> [&'static str; N]`
+//!
+//!   Staticize a fixed-size array of strings, acquiring the lock only once.
+//!
+//! - `staticize_path_components(path: &Path) -> Vec<&'static str>`
+//!
+//!   Staticize each UTF-8 component of a path.
+//!
+//! - `staticize_compact(s: CompactString) -> &'static str`
+//!
+//!   Convert a `CompactString` to a static string without an intermediate
+//!   `String`. Requires the `compact_str` feature.
+//!
+//! - `staticize_formatted_number(n: i64) -> &'static str`
+//!
+//!   Format a number with thousands separators, e.g., `"1,234,567"`, and
+//!   return a static string.
+//!
+//! - `intern_variant_names<T: strum::VariantNames>() -> Vec<&'static str>`
+//!
+//!   Register the variant names of an enum as static strings without
+//!   copying them. Requires the `strum` feature.
+//!
+//! - `staticize_backtrace(bt: &Backtrace) -> &'static str`
+//!
+//!   Format a backtrace and return a static string.
+//!
+//! - `staticize_ulid() -> &'static str`
+//!
+//!   Generate a new ULID and return it as a static string. Requires the
+//!   `ulid` feature.
+//!
+//! - `staticize_header_row(columns: &[(&str, usize)]) -> &'static str`
+//!
+//!   Format a table header row with fixed column widths and return a static
+//!   string.
+//!
+//! - `evict_containing(substring: &str) -> usize`
+//!
+//!   Remove all static strings containing `substring`. Return the number of
+//!   removed strings.
+//!
+//! - `intern_proto_field(field: &str) -> &'static str`
+//!
+//!   Same as `staticize()`, named for protobuf string field values.
+//!
+//! - `staticize_normalize_newlines(s: &str) -> &'static str`
+//!
+//!   Convert `\r\n` and lone `\r` to `\n` and return a static string.
+//!
+//! - `staticize_unless_contains(s: &str, forbidden: &str) -> Option<&'static str>`
+//!
+//!   Staticize a string only if it does not contain `forbidden`.
+//!
+//! - `staticize_yaml_string_seq(s: &str) -> Result<Vec<&'static str>, serde_yaml::Error>`
+//!
+//!   Parse a YAML sequence of strings and staticize each. Requires the
+//!   `yaml` feature.
+//!
+//! - `staticize_colored(s: &str, color: u8) -> &'static str`
+//!
+//!   Wrap a string in ANSI color escape codes and return a static string.
+//!   Requires the `ansi` feature.
+//!
+//! - `staticize_binary_repr(bytes: &[u8]) -> &'static str`
+//!
+//!   Format bytes as space-separated binary numbers, e.g.,
+//!   `"01000001 01000010"`, and return a static string.
+//!
+//! - `intern_once_cell<F: Fn() -> String>(cell: &OnceCell<&'static str>, f: F) -> &'static str`
+//!
+//!   Initialize an externally managed `OnceCell` with the static string of
+//!   `f()`, calling `f` only if the cell is empty.
+//!
+//! - `staticize_first_match(text: &str, re: &regex::Regex) -> Option<&'static str>`
+//!
+//!   Staticize the first match of a regex in a text. Requires the `regex`
+//!   feature.
+//!
+//! - `intern_full_env() -> HashMap<&'static str, &'static str>`
+//!
+//!   Staticize the names and values of all environment variables.
+//!
+//! - `staticize_bullet_list(items: &[&str]) -> &'static str`
+//!
+//!   Format items as a bullet list, e.g., `"• item1\n• item2"`, and return a
+//!   static string.
+//!
+//! - `staticize_number_name(n: u32) -> &'static str`
+//!
+//!   Convert a number to its English words representation, e.g.,
+//!   `"forty-two"`, and return a static string.
+//!
+//! - `staticize_unix_millis(ms: u64) -> &'static str`
+//!
+//!   Format a Unix timestamp in milliseconds as an ISO 8601 UTC date-time
+//!   and return a static string.
+//!
+//! - `staticize_semver(major: u32, minor: u32, patch: u32, pre: &str, build: &str) -> Result<&'static str, semver::Error>`
+//!
+//!   Format and validate a semantic version and return a static string.
+//!   Requires the `semver` feature.
+//!
 //! - `static_concat!(s1: expr, s2: expr, ...) -> &'static str`
 //!
 //!   Concatenate multiple strings into a static string. The arguments can
@@ -68,11 +378,16 @@
 //!
 //!   When you call `make_string()` for multiple times, the body will be
 //!   guaranteed to be evaluated only once.
+//!
+//! - `intern_type_names_for_tuple!(T1, T2, ...) -> Vec<&'static str>`
+//!
+//!   Return the static type names of the given types, e.g., of all the
+//!   components of a tuple type.
 
 #![allow(non_upper_case_globals)]
 
-use std::collections::HashSet;
-use std::sync::Mutex;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, MutexGuard, PoisonError};
 
 use lazy_static::lazy_static;
 
@@ -84,6 +399,70 @@ lazy_static! {
     static ref STATIC_STRINGS: Mutex<HashSet<&'static str>> = Mutex::new(HashSet::new());
 }
 
+#[cfg(feature = "tracing")]
+lazy_static! {
+    static ref SPAN_NAME_CALLSITES: Mutex<
+        HashMap<(&'static str, &'static str, tracing::Level), &'static tracing::Metadata<'static>>,
+    > = Mutex::new(HashMap::new());
+}
+
+/// The number of most recent events kept by the intern event log.
+#[cfg(feature = "audit")]
+pub const INTERN_EVENT_LOG_CAPACITY: usize = 1024;
+
+/// The kind of an operation on the internal set of static strings.
+#[cfg(feature = "audit")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InternEventKind {
+    /// A string was staticized for the first time.
+    New,
+    /// A string was staticized, and the previously staticized string was returned.
+    Hit,
+    /// A static string was removed from the internal set.
+    Evicted,
+}
+
+/// An operation on the internal set of static strings, recorded by the intern event log.
+#[cfg(feature = "audit")]
+#[derive(Debug, Clone, Copy)]
+pub struct InternEvent {
+    pub kind: InternEventKind,
+    pub string: &'static str,
+    pub timestamp: std::time::Instant,
+}
+
+#[cfg(feature = "audit")]
+lazy_static! {
+    static ref INTERN_EVENT_LOG: Mutex<std::collections::VecDeque<InternEvent>> = Mutex::new(
+        std::collections::VecDeque::with_capacity(INTERN_EVENT_LOG_CAPACITY)
+    );
+}
+
+/// Records an event in the intern event log, dropping the oldest one if the log is full.
+#[cfg(feature = "audit")]
+fn record_intern_event(kind: InternEventKind, string: &'static str) {
+    let mut log = INTERN_EVENT_LOG.lock().unwrap();
+    if log.len() == INTERN_EVENT_LOG_CAPACITY {
+        log.pop_front();
+    }
+    log.push_back(InternEvent {
+        kind,
+        string,
+        timestamp: std::time::Instant::now(),
+    });
+}
+
+/// Locks the internal set of static strings.
+///
+/// The set is only mutated by this crate and never left half-updated, so a poisoned lock,
+/// e.g., by a panicking `with_staticized()` callback, is recovered rather than breaking
+/// every later operation.
+fn lock_strings() -> MutexGuard<'static, HashSet<&'static str>> {
+    STATIC_STRINGS
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
+
 /// Converts a string slice to a static string slice.
 ///
 /// This function takes a string slice and returns a static string slice with the same contents.
@@ -106,17 +485,75 @@ lazy_static! {
 /// ```
 pub fn staticize<T: Into<String>>(s: T) -> &'static str {
     let s: Box<String> = Box::new(s.into());
-    let mut strings = STATIC_STRINGS.lock().unwrap();
+    let mut strings = lock_strings();
     match strings.get(s.as_str()) {
-        Some(s) => s,
+        Some(s) => {
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::Hit, s);
+            s
+        }
         None => {
             let s = Box::leak(s);
             strings.insert(s);
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::New, s);
+            s
+        }
+    }
+}
+
+/// Staticizes a string slice into the already locked set of static strings, allowing
+/// batch operations to acquire the internal lock only once.
+fn staticize_locked(strings: &mut HashSet<&'static str>, s: &str) -> &'static str {
+    match strings.get(s) {
+        Some(s) => {
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::Hit, s);
+            s
+        }
+        None => {
+            let s: &'static str = Box::leak(s.into());
+            strings.insert(s);
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::New, s);
+            s
+        }
+    }
+}
+
+/// Registers a string that is already `'static` into the already locked set of static
+/// strings without copying it. If an equal string has been staticized before, that one
+/// is returned instead.
+#[cfg(feature = "strum")]
+fn register_static_locked(strings: &mut HashSet<&'static str>, s: &'static str) -> &'static str {
+    match strings.get(s) {
+        Some(s) => {
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::Hit, s);
+            s
+        }
+        None => {
+            strings.insert(s);
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::New, s);
             s
         }
     }
 }
 
+/// Removes a string from the already locked set of static strings, allowing batch
+/// operations to acquire the internal lock only once.
+fn destaticize_locked(strings: &mut HashSet<&'static str>, s: &str) -> bool {
+    match strings.take(s) {
+        Some(_s) => {
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::Evicted, _s);
+            true
+        }
+        None => false,
+    }
+}
+
 /// Checks if a given string is a static string.
 ///
 /// # Arguments
@@ -127,7 +564,7 @@ pub fn staticize<T: Into<String>>(s: T) -> &'static str {
 ///
 /// Returns `true` if the given string is a static string, `false` otherwise.
 pub fn is_staticized(s: &str) -> bool {
-    STATIC_STRINGS.lock().unwrap().contains(s)
+    lock_strings().contains(s)
 }
 
 /// Removes a static string from the internal set of static strings.
@@ -141,193 +578,3237 @@ pub fn is_staticized(s: &str) -> bool {
 /// A boolean value indicating whether the static string was present.
 ///
 pub fn destaticize(s: &str) -> bool {
-    STATIC_STRINGS.lock().unwrap().remove(s)
+    destaticize_locked(&mut lock_strings(), s)
 }
 
-/// Concatenates the given string literals into a single static string slice.
+/// Repeats a string `n` times, joined by `sep`, and returns the static string slice.
 ///
-/// # Examples
+/// # Arguments
 ///
-/// ```
-/// use static_str_ops::static_concat;
+/// * `s` - The string slice to be repeated.
+/// * `n` - The number of repetitions.
+/// * `sep` - The separator placed between repetitions.
+///
+/// # Examples
 ///
-/// let hello_world: &'static str = static_concat!("Hello", ", ", "world!");
-/// assert_eq!(hello_world, "Hello, world!");
 /// ```
+/// use static_str_ops::staticize_repeat_join;
 ///
-/// # Panics
+/// let placeholders = staticize_repeat_join("?", 5, ", ");
 ///
-/// This macro will panic if any of the input expressions is not a string literal.
-#[macro_export]
-macro_rules! static_concat {
-    ()=>{""};
-    ($($arg: expr),* $(,)?)=>(
-        $crate::staticize(concat!($($arg),*))
-    );
+/// assert_eq!(placeholders, "?, ?, ?, ?, ?");
+/// ```
+pub fn staticize_repeat_join(s: &str, n: usize, sep: &str) -> &'static str {
+    staticize(vec![s; n].join(sep))
 }
 
-/// A macro that takes a format string and arguments, and returns a static string slice.
+/// Parses a record-separator (ASCII `0x1E`) delimited byte array and returns each field
+/// as a static string slice.
+///
+/// All fields are validated as UTF-8 before any of them is staticized, so nothing is
+/// added to the internal set if the input is malformed.
+///
+/// # Arguments
+///
+/// * `data` - The record-separator delimited bytes.
+///
+/// # Returns
+///
+/// The static fields in input order, or the `Utf8Error` of the first invalid field.
 ///
 /// # Examples
 ///
 /// ```
-/// use static_str_ops::static_format;
+/// use static_str_ops::staticize_rs_delimited;
 ///
-/// let name = "John";
-/// let age = 30;
-/// let message = static_format!("My name is {} and I'm {} years old.", name, age);
+/// let fields = staticize_rs_delimited(b"ISA\x1eGS\x1eST").unwrap();
 ///
-/// assert_eq!(message, "My name is John and I'm 30 years old.");
+/// assert_eq!(fields, vec!["ISA", "GS", "ST"]);
 /// ```
-///
-/// This macro is similar to the `format!` macro, but it returns a static string slice
-/// instead of a `String`, and can take whatever the `format!` macro recognizes.
-#[macro_export]
-macro_rules! static_format {
-    ()=>{""};
-    ($($arg: expr),* $(,)?)=>(
-        $crate::staticize(format!($($arg),*))
-    );
+pub fn staticize_rs_delimited(data: &[u8]) -> Result<Vec<&'static str>, std::str::Utf8Error> {
+    let fields = data
+        .split(|b| *b == 0x1E)
+        .map(std::str::from_utf8)
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(fields.into_iter().map(staticize).collect())
 }
 
-/// Internally used by `staticize_once!()`.
-#[doc(hidden)]
-#[macro_export]
-macro_rules! _staticize_once {
-    ($gensym: ident, $expr: expr) => {{
-        use std::sync::Once;
-
-        static mut $gensym: &str = "";
-        static INIT: Once = Once::new();
-        INIT.call_once(|| unsafe {
-            $gensym = staticize($expr);
-        });
-        unsafe { $gensym }
-    }};
+/// Normalizes a string to exactly `width` bytes and returns the static string slice.
+///
+/// Strings longer than `width` are truncated at the nearest char boundary at or below
+/// `width`, and the result is right-padded with spaces up to `width` bytes.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be normalized.
+/// * `width` - The width in bytes of the result.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_fixed_width;
+///
+/// assert_eq!(staticize_fixed_width("hello", 8), "hello   ");
+/// assert_eq!(staticize_fixed_width("hello world", 5), "hello");
+/// ```
+pub fn staticize_fixed_width(s: &str, width: usize) -> &'static str {
+    let mut end = s.len().min(width);
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    let mut fixed = String::with_capacity(width);
+    fixed.push_str(&s[..end]);
+    fixed.push_str(&" ".repeat(width - end));
+    staticize(fixed)
 }
 
-/// Macro to generate a unique identifier for a given expression, which can be used to create a static variable
-/// that is initialized once with the result of the expression. This macro ensures that the expression is only
-/// evaluated once at runtime, and the result is stored in a static variable for future use.
+/// Returns all static strings whose length in bytes is between `min` and `max` (inclusive).
 ///
 /// # Arguments
 ///
-/// * `expr` - An expression of type `&str` or `String`.
+/// * `min` - The minimum length in bytes.
+/// * `max` - The maximum length in bytes.
 ///
 /// # Examples
 ///
 /// ```
-/// #![feature(stmt_expr_attributes)]
+/// use static_str_ops::{all_staticized_between_lengths, staticize};
 ///
-/// use static_str_ops::*;
+/// let s = staticize("hello");
 ///
-/// let s = "hello world";
-/// let static_str = staticize_once!(s.to_uppercase());
-/// assert_eq!(static_str, "HELLO WORLD");
+/// assert!(all_staticized_between_lengths(5, 5).contains(&s));
 /// ```
+pub fn all_staticized_between_lengths(min: usize, max: usize) -> Vec<&'static str> {
+    lock_strings()
+        .iter()
+        .filter(|s| (min..=max).contains(&s.len()))
+        .copied()
+        .collect()
+}
+
+/// Formats a column-aligned ASCII table from headers and rows, and returns the static
+/// string slice.
 ///
-/// # Notes
+/// Columns are separated by `" | "` and the header is underlined with `-`. Each line
+/// ends after its last non-empty cell, which is not padded, so rows with fewer cells
+/// than the table has columns carry no trailing separators. Cell content is kept as is,
+/// including trailing whitespace.
 ///
-/// This macro uses the `gensym` crate to generate a unique identifier for the expression. The `gensym` crate
-/// is a compile-time code generation library that generates unique identifiers based on the source code
-/// location where the macro is invoked. This ensures that the identifier is unique across the entire crate,
-/// and is not accidentally used elsewhere.
-#[macro_export]
+/// # Arguments
+///
+/// * `headers` - The column headers.
+/// * `rows` - The table rows.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_table;
+///
+/// let table = staticize_table(&["name", "age"], &[vec!["alice", "30"], vec!["bob", "4"]]);
+///
+/// assert_eq!(table, "name  | age\n------+----\nalice | 30\nbob   | 4");
+/// ```
+pub fn staticize_table(headers: &[&str], rows: &[Vec<&str>]) -> &'static str {
+    let columns = rows.iter().map(Vec::len).fold(headers.len(), usize::max);
+    if columns == 0 {
+        return staticize("");
+    }
+    let mut widths = vec![0; columns];
+    for row in std::iter::once(headers).chain(rows.iter().map(Vec::as_slice)) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[&str]| {
+        let len = row
+            .iter()
+            .rposition(|cell| !cell.is_empty())
+            .map_or(0, |i| i + 1);
+        let cells = row[..len]
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, width))| {
+                if i + 1 == len {
+                    cell.to_string()
+                } else {
+                    format!("{cell:<width$}")
+                }
+            })
+            .collect::<Vec<_>>();
+        cells.join(" | ")
+    };
+    let mut lines = vec![format_row(headers)];
+    lines.push(
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-+-"),
+    );
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    staticize(lines.join("\n"))
+}
+
+/// Builds a `"k1=v1;k2=v2"` style string from key-value pairs and returns the static
+/// string slice.
+///
+/// # Arguments
+///
+/// * `iter` - The key-value pairs.
+/// * `pair_sep` - The separator placed between pairs, e.g., `;` or `&`.
+/// * `kv_sep` - The separator placed between a key and its value, e.g., `=`.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_kv_pairs;
+///
+/// let query = staticize_kv_pairs([("q", "rust"), ("page", "2")].into_iter(), '&', '=');
+///
+/// assert_eq!(query, "q=rust&page=2");
+/// ```
+pub fn staticize_kv_pairs<'a, I: Iterator<Item = (&'a str, &'a str)>>(
+    iter: I,
+    pair_sep: char,
+    kv_sep: char,
+) -> &'static str {
+    let mut s = String::new();
+    for (i, (k, v)) in iter.enumerate() {
+        if i > 0 {
+            s.push(pair_sep);
+        }
+        s.push_str(k);
+        s.push(kv_sep);
+        s.push_str(v);
+    }
+    staticize(s)
+}
+
+/// Checks if all the given strings are static strings.
+///
+/// Unlike calling `is_staticized()` in a loop, the internal lock is acquired only once.
+///
+/// # Arguments
+///
+/// * `strings` - The string slices to check.
+///
+/// # Returns
+///
+/// Returns `true` if every given string is a static string (or `strings` is empty),
+/// `false` otherwise.
+pub fn is_subset_interned(strings: &[&str]) -> bool {
+    let static_strings = lock_strings();
+    strings.iter().all(|s| static_strings.contains(s))
+}
+
+/// Staticizes each unique string once, and returns the static string slices in
+/// first-seen order.
+///
+/// The internal lock is acquired only once for all the strings.
+///
+/// # Arguments
+///
+/// * `strings` - The string slices to be converted, possibly with duplicates.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_deduplicated;
+///
+/// let names = staticize_deduplicated(&["Accept", "Host", "Accept", "Cookie", "Host"]);
+///
+/// assert_eq!(names, vec!["Accept", "Host", "Cookie"]);
+/// ```
+pub fn staticize_deduplicated(strings: &[&str]) -> Vec<&'static str> {
+    let mut static_strings = lock_strings();
+    let mut seen = HashSet::new();
+    strings
+        .iter()
+        .filter(|s| seen.insert(**s))
+        .map(|s| staticize_locked(&mut static_strings, s))
+        .collect()
+}
+
+/// Sorts the given strings lexicographically, staticizes each of them, and returns the
+/// static string slices in sorted order.
+///
+/// The internal lock is acquired only once for all the strings.
+///
+/// # Arguments
+///
+/// * `strings` - The string slices to be converted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_sorted;
+///
+/// let labels = staticize_sorted(&["region", "env", "host"]);
+///
+/// assert_eq!(labels, vec!["env", "host", "region"]);
+/// ```
+pub fn staticize_sorted(strings: &[&str]) -> Vec<&'static str> {
+    let mut sorted = strings.to_vec();
+    sorted.sort_unstable();
+    let mut static_strings = lock_strings();
+    sorted
+        .into_iter()
+        .map(|s| staticize_locked(&mut static_strings, s))
+        .collect()
+}
+
+/// Converts a string slice to a static string slice, and returns it together with its
+/// character count.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to be converted to a static string slice.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_with_char_count;
+///
+/// let (s, count) = staticize_with_char_count("caf\u{e9}");
+///
+/// assert_eq!(s, "caf\u{e9}");
+/// assert_eq!(count, 4);
+/// ```
+pub fn staticize_with_char_count(s: &str) -> (&'static str, usize) {
+    (staticize(s), s.chars().count())
+}
+
+/// Removes a set of static strings from the internal set of static strings.
+///
+/// Unlike calling `destaticize()` in a loop, the internal lock is acquired only once.
+///
+/// # Arguments
+///
+/// * `to_remove` - The static strings to be removed.
+///
+/// # Returns
+///
+/// The number of static strings that were present and have been removed.
+pub fn destaticize_set(to_remove: &HashSet<&str>) -> usize {
+    let mut strings = lock_strings();
+    to_remove
+        .iter()
+        .filter(|s| destaticize_locked(&mut strings, s))
+        .count()
+}
+
+/// Splits a string into chunks of at most `chunk_size` bytes and staticizes each chunk.
+///
+/// Chunks never split a UTF-8 character: a chunk ends at the last char boundary within
+/// `chunk_size` bytes, or after the first character if that character alone is longer
+/// than `chunk_size`.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be split.
+/// * `chunk_size` - The maximum size in bytes of each chunk.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_chunks;
+///
+/// let chunks = staticize_chunks("hello world", 4);
+///
+/// assert_eq!(chunks, vec!["hell", "o wo", "rld"]);
+/// ```
+///
+/// # Panics
+///
+/// This function will panic if `chunk_size` is zero.
+pub fn staticize_chunks(s: &str, chunk_size: usize) -> Vec<&'static str> {
+    assert!(chunk_size != 0, "chunk size must be non-zero");
+    let mut static_strings = lock_strings();
+    let mut chunks = Vec::new();
+    let mut rest = s;
+    while !rest.is_empty() {
+        let mut end = rest.len().min(chunk_size);
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        if end == 0 {
+            end = rest.chars().next().map_or(0, char::len_utf8);
+        }
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(staticize_locked(&mut static_strings, chunk));
+        rest = tail;
+    }
+    chunks
+}
+
+/// Formats a bit count as a binary size string, e.g., `"1.50 Kib"`, and returns the
+/// static string slice.
+///
+/// Counts below 1024 are formatted as whole bits (`"512 b"`), larger counts are scaled
+/// by powers of 1024 up to `Tib` and formatted with two decimal places.
+///
+/// # Arguments
+///
+/// * `bits` - The number of bits.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_binary_size;
+///
+/// assert_eq!(staticize_binary_size(512), "512 b");
+/// assert_eq!(staticize_binary_size(1536), "1.50 Kib");
+/// assert_eq!(staticize_binary_size(8 * 1024 * 1024), "8.00 Mib");
+/// ```
+pub fn staticize_binary_size(bits: u64) -> &'static str {
+    const UNITS: [&str; 4] = ["Kib", "Mib", "Gib", "Tib"];
+
+    if bits < 1024 {
+        return staticize(format!("{} b", bits));
+    }
+    let mut size = bits as f64 / 1024.0;
+    let mut unit = 0;
+    // promote values that would round up to 1024.00 as well
+    while size >= 1024.0 - 0.005 && unit + 1 < UNITS.len() {
+        size /= 1024.0;
+        unit += 1;
+    }
+    staticize(format!("{:.2} {}", size, UNITS[unit]))
+}
+
+/// Converts a number to its Roman numeral representation and returns the static string
+/// slice.
+///
+/// # Arguments
+///
+/// * `n` - The number to be converted, in the range `1..=3999`.
+///
+/// # Returns
+///
+/// The static Roman numeral, or `None` if `n` is zero or greater than 3999.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_roman;
+///
+/// assert_eq!(staticize_roman(2024), Some("MMXXIV"));
+/// assert_eq!(staticize_roman(0), None);
+/// ```
+pub fn staticize_roman(n: u32) -> Option<&'static str> {
+    const NUMERALS: [(u32, &str); 13] = [
+        (1000, "M"),
+        (900, "CM"),
+        (500, "D"),
+        (400, "CD"),
+        (100, "C"),
+        (90, "XC"),
+        (50, "L"),
+        (40, "XL"),
+        (10, "X"),
+        (9, "IX"),
+        (5, "V"),
+        (4, "IV"),
+        (1, "I"),
+    ];
+
+    if !(1..=3999).contains(&n) {
+        return None;
+    }
+    let mut n = n;
+    let mut roman = String::new();
+    for (value, numeral) in NUMERALS {
+        while n >= value {
+            roman.push_str(numeral);
+            n -= value;
+        }
+    }
+    Some(staticize(roman))
+}
+
+/// Returns the English ordinal suffix of a number, i.e., `"st"`, `"nd"`, `"rd"` or `"th"`.
+fn ordinal_suffix(n: u64) -> &'static str {
+    match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
+
+/// Formats a number as an English ordinal, e.g., `"1st"`, `"2nd"`, `"3rd"`, and
+/// returns the static string slice.
+///
+/// # Arguments
+///
+/// * `n` - The number to be formatted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_ordinal;
+///
+/// assert_eq!(staticize_ordinal(1), "1st");
+/// assert_eq!(staticize_ordinal(12), "12th");
+/// assert_eq!(staticize_ordinal(23), "23rd");
+/// ```
+pub fn staticize_ordinal(n: u64) -> &'static str {
+    staticize(format!("{}{}", n, ordinal_suffix(n)))
+}
+
+/// Deserializes a `bincode`-encoded string and returns the static string slice.
+///
+/// This function is only available with the `bincode` feature.
+///
+/// # Arguments
+///
+/// * `bytes` - The `bincode`-encoded string.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "bincode")]
+/// # {
+/// use static_str_ops::staticize_bincode;
+///
+/// let bytes = bincode::serialize("hello").unwrap();
+///
+/// assert_eq!(staticize_bincode(&bytes).unwrap(), "hello");
+/// # }
+/// ```
+#[cfg(feature = "bincode")]
+pub fn staticize_bincode(bytes: &[u8]) -> Result<&'static str, bincode::Error> {
+    bincode::deserialize::<&str>(bytes).map(staticize)
+}
+
+/// A `tracing` callsite registered at runtime for a staticized span name.
+#[cfg(feature = "tracing")]
+struct SpanNameCallsite(std::sync::OnceLock<tracing::Metadata<'static>>);
+
+#[cfg(feature = "tracing")]
+impl tracing::callsite::Callsite for SpanNameCallsite {
+    fn set_interest(&self, _interest: tracing::subscriber::Interest) {}
+
+    fn metadata(&self) -> &tracing::Metadata<'_> {
+        self.0
+            .get()
+            .expect("span name callsite is initialized before registration")
+    }
+}
+
+/// Converts a span name to a static string slice, and registers a `tracing` span
+/// callsite with that name.
+///
+/// `tracing` requires callsite names and metadata to be `'static`. The returned metadata
+/// can be passed to `tracing::Span::new()` to create spans from this callsite, and its
+/// `name()` is the static span name.
+///
+/// A callsite is registered only the first time a `(name, target, level)` combination is
+/// seen, subsequent calls return the same metadata.
+///
+/// This function is only available with the `tracing` feature.
+///
+/// # Arguments
+///
+/// * `name` - The span name.
+/// * `target` - The span target, e.g., a module path.
+/// * `level` - The span level.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "tracing")]
+/// # {
+/// use static_str_ops::staticize_span_name;
+///
+/// let metadata = staticize_span_name("handle_request", "my_app::server", tracing::Level::INFO);
+/// let span = tracing::Span::new(metadata, &metadata.fields().value_set(&[]));
+///
+/// assert_eq!(metadata.name(), "handle_request");
+/// # }
+/// ```
+#[cfg(feature = "tracing")]
+pub fn staticize_span_name(
+    name: &str,
+    target: &str,
+    level: tracing::Level,
+) -> &'static tracing::Metadata<'static> {
+    use tracing::callsite::{Callsite, Identifier};
+    use tracing::field::FieldSet;
+    use tracing::metadata::Kind;
+
+    let (name, target) = {
+        let mut static_strings = lock_strings();
+        (
+            staticize_locked(&mut static_strings, name),
+            staticize_locked(&mut static_strings, target),
+        )
+    };
+    let mut callsites = SPAN_NAME_CALLSITES.lock().unwrap();
+    if let Some(metadata) = callsites.get(&(name, target, level)) {
+        return metadata;
+    }
+
+    let callsite: &'static SpanNameCallsite =
+        Box::leak(Box::new(SpanNameCallsite(std::sync::OnceLock::new())));
+    let metadata = callsite.0.get_or_init(|| {
+        tracing::Metadata::new(
+            name,
+            target,
+            level,
+            None,
+            None,
+            None,
+            FieldSet::new(&[], Identifier(callsite as &'static dyn Callsite)),
+            Kind::SPAN,
+        )
+    });
+    tracing::callsite::register(callsite);
+    callsites.insert((name, target, level), metadata);
+    metadata
+}
+
+/// Word-wraps a string at `width` characters and staticizes each line.
+///
+/// Each line of the input is wrapped separately, so hard line breaks are kept and blank
+/// lines are returned as `""`. Within a line, words are split at whitespace, and
+/// consecutive whitespace is collapsed into a single space. A word longer than `width`
+/// is kept intact on its own line.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be wrapped.
+/// * `width` - The maximum number of characters per line.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_word_wrap;
+///
+/// let lines = staticize_word_wrap("the quick brown fox jumps", 10);
+///
+/// assert_eq!(lines, vec!["the quick", "brown fox", "jumps"]);
+/// ```
+pub fn staticize_word_wrap(s: &str, width: usize) -> Vec<&'static str> {
+    let mut lines = Vec::new();
+    for input_line in s.lines() {
+        let mut line = String::new();
+        let mut line_width = 0;
+        for word in input_line.split_whitespace() {
+            let word_width = word.chars().count();
+            if !line.is_empty() && line_width + 1 + word_width > width {
+                lines.push(std::mem::take(&mut line));
+                line_width = 0;
+            }
+            if !line.is_empty() {
+                line.push(' ');
+                line_width += 1;
+            }
+            line.push_str(word);
+            line_width += word_width;
+        }
+        lines.push(line);
+    }
+
+    let mut static_strings = lock_strings();
+    lines
+        .iter()
+        .map(|line| staticize_locked(&mut static_strings, line))
+        .collect()
+}
+
+/// Returns all static strings that consist of ASCII characters only.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::{all_staticized_ascii, staticize};
+///
+/// let s = staticize("hello");
+///
+/// assert!(all_staticized_ascii().contains(&s));
+/// ```
+pub fn all_staticized_ascii() -> Vec<&'static str> {
+    lock_strings()
+        .iter()
+        .filter(|s| s.is_ascii())
+        .copied()
+        .collect()
+}
+
+/// Returns all static strings that contain at least one non-ASCII character.
+///
+/// This is the complement of `all_staticized_ascii()`.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::{all_staticized_non_ascii, staticize};
+///
+/// let s = staticize("caf\u{e9}");
+///
+/// assert!(all_staticized_non_ascii().contains(&s));
+/// ```
+pub fn all_staticized_non_ascii() -> Vec<&'static str> {
+    lock_strings()
+        .iter()
+        .filter(|s| !s.is_ascii())
+        .copied()
+        .collect()
+}
+
+/// Decodes a protobuf message holding a `string` in field 1 (the layout of
+/// `google.protobuf.StringValue`) from raw bytes, and returns the static string slice.
+///
+/// The whole input is decoded as a message: other field numbers are skipped as unknown
+/// fields, and a repeated field 1 keeps the last value. An empty input decodes to the
+/// empty string. Field 1 with a wrong wire type, truncated input, or invalid UTF-8 is
+/// an error.
+///
+/// This function is only available with the `prost` feature.
+///
+/// # Arguments
+///
+/// * `bytes` - The encoded protobuf field.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "prost")]
+/// # {
+/// use static_str_ops::staticize_proto_string;
+///
+/// // field 1, wire type 2, length 5, "hello"
+/// let bytes = b"\x0a\x05hello";
+///
+/// assert_eq!(staticize_proto_string(bytes).unwrap(), "hello");
+/// # }
+/// ```
+#[cfg(feature = "prost")]
+pub fn staticize_proto_string(bytes: &[u8]) -> Result<&'static str, prost::DecodeError> {
+    <String as prost::Message>::decode(bytes).map(staticize)
+}
+
+/// Escapes the Markdown special characters in a string with backslashes, and returns
+/// the static string slice.
+///
+/// The escaped characters are `` \ ` * _ { } [ ] ( ) # + - . ! | < > ~ ``.
+///
+/// This function is only available with the `markdown` feature.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be escaped.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "markdown")]
+/// # {
+/// use static_str_ops::staticize_markdown_escaped;
+///
+/// assert_eq!(staticize_markdown_escaped("*bold* [link]"), r"\*bold\* \[link\]");
+/// # }
+/// ```
+#[cfg(feature = "markdown")]
+pub fn staticize_markdown_escaped(s: &str) -> &'static str {
+    const SPECIAL: &str = "\\`*_{}[]()#+-.!|<>~";
+
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        if SPECIAL.contains(c) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    staticize(escaped)
+}
+
+/// Converts a string slice to a static string slice, falling back to the string
+/// produced by `fallback` if `s` is empty.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to be converted to a static string slice.
+/// * `fallback` - Produces the string to be converted when `s` is empty, it is not
+///   called otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_with_fallback;
+///
+/// assert_eq!(staticize_with_fallback("", || String::from("localhost")), "localhost");
+/// assert_eq!(staticize_with_fallback("example.com", || String::from("localhost")), "example.com");
+/// ```
+pub fn staticize_with_fallback(s: &str, fallback: impl FnOnce() -> String) -> &'static str {
+    if s.is_empty() {
+        staticize(fallback())
+    } else {
+        staticize(s)
+    }
+}
+
+/// Removes all static strings that start with any of the given prefixes from the
+/// internal set of static strings.
+///
+/// The internal lock is acquired only once for all the prefixes. An empty prefix
+/// removes nothing, rather than every static string.
+///
+/// # Arguments
+///
+/// * `prefixes` - The prefixes of the static strings to be removed.
+///
+/// # Returns
+///
+/// The number of removed static strings for each prefix. A string matching several
+/// prefixes is counted only for the first of them in `prefixes`.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::{evict_prefixes, staticize};
+///
+/// let _ = staticize("tmp.a");
+/// let _ = staticize("tmp.b");
+///
+/// let removed = evict_prefixes(&["tmp.", "cache."]);
+///
+/// assert_eq!(removed["tmp."], 2);
+/// assert_eq!(removed["cache."], 0);
+/// ```
+pub fn evict_prefixes<'a>(prefixes: &'a [&'a str]) -> HashMap<&'a str, usize> {
+    let mut removed: HashMap<&str, usize> = prefixes.iter().map(|p| (*p, 0)).collect();
+    lock_strings().retain(|s| {
+        match prefixes
+            .iter()
+            .find(|p| !p.is_empty() && s.starts_with(**p))
+        {
+            Some(p) => {
+                *removed.get_mut(p).unwrap() += 1;
+                #[cfg(feature = "audit")]
+                record_intern_event(InternEventKind::Evicted, s);
+                false
+            }
+            None => true,
+        }
+    });
+    removed
+}
+
+/// Converts a UTF-8 byte slice to a static string slice.
+///
+/// This is a named shorthand for `std::str::from_utf8(bytes).map(staticize)`.
+///
+/// # Arguments
+///
+/// * `bytes` - The UTF-8 bytes to be converted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_utf8;
+///
+/// assert_eq!(staticize_utf8(b"hello").unwrap(), "hello");
+/// assert!(staticize_utf8(b"\xff").is_err());
+/// ```
+pub fn staticize_utf8(bytes: &[u8]) -> Result<&'static str, std::str::Utf8Error> {
+    std::str::from_utf8(bytes).map(staticize)
+}
+
+/// Hashes a value with the standard `DefaultHasher`, and returns the hash formatted as
+/// an uppercase hex string, e.g., `"0x00FF12AB34CD56EF"`, as a static string slice.
+///
+/// `DefaultHasher::new()` uses fixed keys, so equal values produce the same string
+/// within a program, but the string may change across Rust releases.
+///
+/// # Arguments
+///
+/// * `val` - The value to be hashed.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_hash;
+///
+/// let key = staticize_hash(&(42, "answer"));
+///
+/// assert_eq!(key.len(), 18);
+/// assert_eq!(key, staticize_hash(&(42, "answer")));
+/// ```
+pub fn staticize_hash<H: std::hash::Hash>(val: &H) -> &'static str {
+    use std::hash::Hasher;
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    val.hash(&mut hasher);
+    staticize(format!("0x{:016X}", hasher.finish()))
+}
+
+/// Converts `t` if `cond` is true, or `f` otherwise, to a static string slice.
+///
+/// # Arguments
+///
+/// * `cond` - The condition.
+/// * `t` - The string slice to be converted when `cond` is true.
+/// * `f` - The string slice to be converted when `cond` is false.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_conditional;
+///
+/// let enabled = true;
+///
+/// assert_eq!(staticize_conditional(enabled, "on", "off"), "on");
+/// ```
+pub fn staticize_conditional(cond: bool, t: &str, f: &str) -> &'static str {
+    if cond {
+        staticize(t)
+    } else {
+        staticize(f)
+    }
+}
+
+/// Looks up `key` in `map`, inserting the string produced by `default` if it is absent,
+/// and returns the value as a static string slice.
+///
+/// # Arguments
+///
+/// * `map` - The map to look up.
+/// * `key` - The key to look up.
+/// * `default` - Produces the value to be inserted when `key` is absent, it is not
+///   called otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+/// use static_str_ops::intern_map_entry;
+///
+/// let mut names = HashMap::new();
+///
+/// assert_eq!(intern_map_entry(&mut names, 1, || String::from("one")), "one");
+/// assert_eq!(intern_map_entry(&mut names, 1, || String::from("uno")), "one");
+/// assert_eq!(names[&1], "one");
+/// ```
+pub fn intern_map_entry<K: Eq + std::hash::Hash>(
+    map: &mut HashMap<K, String>,
+    key: K,
+    default: impl FnOnce() -> String,
+) -> &'static str {
+    staticize(map.entry(key).or_insert_with(default).as_str())
+}
+
+/// Formats a `key=value` pair and returns the static string slice.
+///
+/// # Arguments
+///
+/// * `key` - The key.
+/// * `value` - The value.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_kv;
+///
+/// assert_eq!(staticize_kv("user_id", "123"), "user_id=123");
+/// ```
+pub fn staticize_kv(key: &str, value: &str) -> &'static str {
+    staticize(format!("{}={}", key, value))
+}
+
+/// Quotes and escapes a string as a JSON string literal (RFC 7159), and returns the
+/// static string slice.
+///
+/// `"` and `\` are backslash-escaped, control characters are escaped with their short
+/// form (e.g., `\n`) if any, or as `\u00XX` otherwise.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be quoted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_json_string_literal;
+///
+/// assert_eq!(staticize_json_string_literal("say \"hi\"\n"), r#""say \"hi\"\n""#);
+/// ```
+pub fn staticize_json_string_literal(s: &str) -> &'static str {
+    use std::fmt::Write;
+
+    let mut literal = String::with_capacity(s.len() + 2);
+    literal.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => literal.push_str("\\\""),
+            '\\' => literal.push_str("\\\\"),
+            '\n' => literal.push_str("\\n"),
+            '\r' => literal.push_str("\\r"),
+            '\t' => literal.push_str("\\t"),
+            '\u{08}' => literal.push_str("\\b"),
+            '\u{0c}' => literal.push_str("\\f"),
+            c if c < ' ' => write!(literal, "\\u{:04x}", c as u32).unwrap(),
+            c => literal.push(c),
+        }
+    }
+    literal.push('"');
+    staticize(literal)
+}
+
+/// Reads up to `max_lines` lines from a reader, and staticizes each non-empty line.
+///
+/// Reading stops at EOF or after `max_lines` lines, empty lines count towards the limit
+/// but are skipped. Line endings (`\n` or `\r\n`) are not part of the static strings.
+///
+/// # Arguments
+///
+/// * `r` - The reader to read lines from.
+/// * `max_lines` - The maximum number of lines to read.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_from_reader_line_by_line;
+///
+/// let input = "alpha\n\nbeta\r\ngamma\n";
+/// let lines = staticize_from_reader_line_by_line(input.as_bytes(), 3).unwrap();
+///
+/// assert_eq!(lines, vec!["alpha", "beta"]);
+/// ```
+pub fn staticize_from_reader_line_by_line<R: std::io::BufRead>(
+    r: R,
+    max_lines: usize,
+) -> std::io::Result<Vec<&'static str>> {
+    let lines = r
+        .lines()
+        .take(max_lines)
+        .collect::<std::io::Result<Vec<_>>>()?;
+    let mut static_strings = lock_strings();
+    Ok(lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| staticize_locked(&mut static_strings, line))
+        .collect())
+}
+
+/// Splits a string at the first occurrence of `pat`, and converts both halves to static
+/// string slices.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be split.
+/// * `pat` - The delimiter, which is not part of either half.
+///
+/// # Returns
+///
+/// The static halves before and after `pat`, or `None` if `pat` is not found.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_split_once;
+///
+/// let header = staticize_split_once("Content-Type: text/html", ':');
+///
+/// assert_eq!(header, Some(("Content-Type", " text/html")));
+/// ```
+pub fn staticize_split_once(s: &str, pat: char) -> Option<(&'static str, &'static str)> {
+    let (first, second) = s.split_once(pat)?;
+    let mut static_strings = lock_strings();
+    Some((
+        staticize_locked(&mut static_strings, first),
+        staticize_locked(&mut static_strings, second),
+    ))
+}
+
+/// Prepends `prefix` to a string and converts the result to a static string slice,
+/// reporting whether it is new.
+///
+/// The internal lock is acquired only once for both the lookup and the insertion.
+///
+/// # Arguments
+///
+/// * `prefix` - The prefix to prepend.
+/// * `s` - The string slice to be prefixed.
+///
+/// # Returns
+///
+/// The static prefixed string, and `true` if it had not been staticized before.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_prefixed_new;
+///
+/// assert_eq!(staticize_prefixed_new("metric.", "requests"), ("metric.requests", true));
+/// assert_eq!(staticize_prefixed_new("metric.", "requests"), ("metric.requests", false));
+/// ```
+pub fn staticize_prefixed_new(prefix: &'static str, s: &str) -> (&'static str, bool) {
+    let prefixed = format!("{}{}", prefix, s);
+    let mut static_strings = lock_strings();
+    let is_new = !static_strings.contains(prefixed.as_str());
+    (staticize_locked(&mut static_strings, &prefixed), is_new)
+}
+
+/// Calls `f` with a reference to the internal set of static strings, and returns its
+/// result.
+///
+/// The internal lock is held for the duration of `f`, so `f` observes a consistent set
+/// without copying it.
+///
+/// # Arguments
+///
+/// * `f` - The function to be called with the set of static strings.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::{staticize, with_staticized};
+///
+/// let _ = staticize("hello");
+///
+/// assert!(with_staticized(|strings| strings.contains("hello")));
+/// ```
+///
+/// # Notes
+///
+/// `f` must not call other functions of this crate, e.g., `staticize()`, as the lock is
+/// not reentrant and that would deadlock.
+///
+/// # Panics
+///
+/// A panic in `f` is propagated to the caller. The internal set is left intact, and
+/// later operations of this crate keep working.
+pub fn with_staticized<F, R>(f: F) -> R
+where
+    F: FnOnce(&HashSet<&'static str>) -> R,
+{
+    f(&lock_strings())
+}
+
+/// Formats a number of seconds as a duration string, e.g., `"1h 2m 3.4s"`, and returns
+/// the static string slice.
+///
+/// Seconds are rounded to one decimal place, and leading zero hours and minutes are
+/// omitted. Negative durations are prefixed with `-` unless they round to zero.
+/// Non-finite values, and values too large to be split into hours (above about
+/// `u64::MAX / 10` seconds), are formatted as plain seconds, e.g., `"infs"`.
+///
+/// # Arguments
+///
+/// * `secs` - The number of seconds.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_duration_secs;
+///
+/// assert_eq!(staticize_duration_secs(3723.4), "1h 2m 3.4s");
+/// assert_eq!(staticize_duration_secs(62.5), "1m 2.5s");
+/// assert_eq!(staticize_duration_secs(0.25), "0.3s");
+/// ```
+pub fn staticize_duration_secs(secs: f64) -> &'static str {
+    let tenths = (secs.abs() * 10.0).round();
+    if !secs.is_finite() || tenths >= u64::MAX as f64 {
+        return staticize(format!("{}s", secs));
+    }
+    let tenths = tenths as u64;
+    let sign = if secs < 0.0 && tenths > 0 { "-" } else { "" };
+    let (hours, minutes) = (tenths / 36000, tenths / 600 % 60);
+    let seconds = format!("{}.{}s", tenths / 10 % 60, tenths % 10);
+    staticize(if hours > 0 {
+        format!("{}{}h {}m {}", sign, hours, minutes, seconds)
+    } else if minutes > 0 {
+        format!("{}{}m {}", sign, minutes, seconds)
+    } else {
+        format!("{}{}", sign, seconds)
+    })
+}
+
+/// Formats `n / d` as a percentage with two decimal places, e.g., `"42.50%"`, and
+/// returns the static string slice.
+///
+/// # Arguments
+///
+/// * `n` - The numerator.
+/// * `d` - The denominator.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_percentage;
+///
+/// assert_eq!(staticize_percentage(17.0, 40.0), "42.50%");
+/// ```
+pub fn staticize_percentage(n: f64, d: f64) -> &'static str {
+    staticize(format!("{:.2}%", 100.0 * n / d))
+}
+
+/// Returns the `n` most recent events of the intern event log, oldest first.
+///
+/// The log keeps the last `INTERN_EVENT_LOG_CAPACITY` operations on the internal set of
+/// static strings: new strings, hits on previously staticized strings, and evictions.
+///
+/// This function is only available with the `audit` feature.
+///
+/// # Arguments
+///
+/// * `n` - The maximum number of events to return.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "audit")]
+/// # {
+/// use static_str_ops::{intern_event_log, staticize, InternEventKind};
+///
+/// let s = staticize("audited");
+/// let events = intern_event_log(16);
+///
+/// assert!(events.iter().any(|e| e.string == s && e.kind == InternEventKind::New));
+/// # }
+/// ```
+#[cfg(feature = "audit")]
+pub fn intern_event_log(n: usize) -> Vec<InternEvent> {
+    let log = INTERN_EVENT_LOG.lock().unwrap();
+    log.iter()
+        .skip(log.len().saturating_sub(n))
+        .copied()
+        .collect()
+}
+
+/// Converts a string slice to a static string slice, like `staticize()`, for code that
+/// runs once at startup, e.g., from `main()`.
+///
+/// The behavior is identical to `staticize()`, but the path creating a new static string
+/// is marked as `#[cold]`, hinting the optimizer that it is rarely taken, so it stays
+/// out of the way of hot code around the call site.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to be converted to a static string slice.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_startup;
+///
+/// let app_name = staticize_startup("my-app");
+///
+/// assert_eq!(app_name, "my-app");
+/// ```
+pub fn staticize_startup(s: &str) -> &'static str {
+    #[cold]
+    fn staticize_miss(strings: &mut HashSet<&'static str>, s: &str) -> &'static str {
+        staticize_locked(strings, s)
+    }
+
+    let mut strings = lock_strings();
+    match strings.get(s) {
+        Some(s) => {
+            #[cfg(feature = "audit")]
+            record_intern_event(InternEventKind::Hit, s);
+            s
+        }
+        None => staticize_miss(&mut strings, s),
+    }
+}
+
+/// Reads an environment variable containing a separated list, e.g., `PATH`, and
+/// staticizes each component.
+///
+/// # Arguments
+///
+/// * `name` - The name of the environment variable.
+/// * `sep` - The separator between components, e.g., `:`.
+///
+/// # Returns
+///
+/// The static components, or `None` if the variable is not set or is not valid unicode.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_env_var_split;
+///
+/// std::env::set_var("PLUGIN_PATH", "/usr/lib/plugins:/opt/plugins");
+///
+/// assert_eq!(
+///     staticize_env_var_split("PLUGIN_PATH", ':'),
+///     Some(vec!["/usr/lib/plugins", "/opt/plugins"])
+/// );
+/// ```
+pub fn staticize_env_var_split(name: &str, sep: char) -> Option<Vec<&'static str>> {
+    let value = std::env::var(name).ok()?;
+    let mut static_strings = lock_strings();
+    Some(
+        value
+            .split(sep)
+            .map(|part| staticize_locked(&mut static_strings, part))
+            .collect(),
+    )
+}
+
+/// Formats bytes as space-separated uppercase hex pairs, e.g., `"DE AD BE EF"`, and
+/// returns the static string slice.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to be formatted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_hex_dump;
+///
+/// assert_eq!(staticize_hex_dump(&[0xde, 0xad, 0xbe, 0xef]), "DE AD BE EF");
+/// ```
+pub fn staticize_hex_dump(bytes: &[u8]) -> &'static str {
+    staticize(
+        bytes
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Formats a 6-byte Ethernet MAC address as `"AA:BB:CC:DD:EE:FF"`, and returns the
+/// static string slice.
+///
+/// # Arguments
+///
+/// * `bytes` - The MAC address.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_mac_addr;
+///
+/// let mac = staticize_mac_addr(&[0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]);
+///
+/// assert_eq!(mac, "00:1A:2B:3C:4D:5E");
+/// ```
+pub fn staticize_mac_addr(bytes: &[u8; 6]) -> &'static str {
+    let [a, b, c, d, e, f] = bytes;
+    staticize(format!(
+        "{:02X}:{:02X}:{:02X}:{:02X}:{:02X}:{:02X}",
+        a, b, c, d, e, f
+    ))
+}
+
+/// Returns the name of a type without module paths, e.g., `"Vec<String>"` rather than
+/// `"alloc::vec::Vec<alloc::string::String>"`, as a static string slice.
+///
+/// Every path in the name returned by `std::any::type_name()`, including those of
+/// generic arguments, is shortened to its last component.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::intern_short_type_name;
+///
+/// assert_eq!(intern_short_type_name::<Vec<String>>(), "Vec<String>");
+/// assert_eq!(intern_short_type_name::<Option<&str>>(), "Option<&str>");
+/// ```
+pub fn intern_short_type_name<T: ?Sized>() -> &'static str {
+    let name = std::any::type_name::<T>();
+    let mut short = String::with_capacity(name.len());
+    let mut path = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap_or_default());
+    staticize(short)
+}
+
+/// Formats a value with both `Display` and `Debug`, and returns the two static string
+/// slices.
+///
+/// The internal lock is acquired only once for both strings.
+///
+/// # Arguments
+///
+/// * `val` - The value to be formatted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_display_and_debug;
+///
+/// let (display, debug) = staticize_display_and_debug(&"hello");
+///
+/// assert_eq!(display, "hello");
+/// assert_eq!(debug, "\"hello\"");
+/// ```
+pub fn staticize_display_and_debug<T: std::fmt::Display + std::fmt::Debug>(
+    val: &T,
+) -> (&'static str, &'static str) {
+    let (display, debug) = (val.to_string(), format!("{:?}", val));
+    let mut static_strings = lock_strings();
+    (
+        staticize_locked(&mut static_strings, &display),
+        staticize_locked(&mut static_strings, &debug),
+    )
+}
+
+/// Converts a fixed-size array of string slices to an array of static string slices.
+///
+/// The internal lock is acquired only once for all the strings.
+///
+/// # Arguments
+///
+/// * `arr` - The string slices to be converted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::intern_array;
+///
+/// let [method, path] = intern_array(["GET", "/index.html"]);
+///
+/// assert_eq!((method, path), ("GET", "/index.html"));
+/// ```
+pub fn intern_array<const N: usize>(arr: [&str; N]) -> [&'static str; N] {
+    let mut static_strings = lock_strings();
+    arr.map(|s| staticize_locked(&mut static_strings, s))
+}
+
+/// Staticizes each component of a path, skipping components that are not valid UTF-8.
+///
+/// Components are produced by `Path::components()`, so the root directory is `"/"` on
+/// Unix, and redundant separators and `.` components are normalized away.
+///
+/// # Arguments
+///
+/// * `path` - The path to be split.
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+/// use static_str_ops::staticize_path_components;
+///
+/// let components = staticize_path_components(Path::new("src//lib.rs"));
+///
+/// assert_eq!(components, vec!["src", "lib.rs"]);
+/// ```
+pub fn staticize_path_components(path: &std::path::Path) -> Vec<&'static str> {
+    let mut static_strings = lock_strings();
+    path.components()
+        .filter_map(|component| component.as_os_str().to_str())
+        .map(|component| staticize_locked(&mut static_strings, component))
+        .collect()
+}
+
+/// Converts a `CompactString` to a static string slice.
+///
+/// Unlike `staticize()`, no intermediate `String` is built: the string is looked up
+/// as is, and only if it has not been staticized before, its contents (whether stored
+/// inline or on the heap) are copied once into an exactly sized `Box<str>`.
+///
+/// This function is only available with the `compact_str` feature.
+///
+/// # Arguments
+///
+/// * `s` - The `CompactString` to be converted.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "compact_str")]
+/// # {
+/// use compact_str::CompactString;
+/// use static_str_ops::staticize_compact;
+///
+/// assert_eq!(staticize_compact(CompactString::new("hello")), "hello");
+/// # }
+/// ```
+#[cfg(feature = "compact_str")]
+pub fn staticize_compact(s: compact_str::CompactString) -> &'static str {
+    staticize_locked(&mut lock_strings(), s.as_str())
+}
+
+/// Formats a number with thousands separators, e.g., `"1,234,567"`, and returns the
+/// static string slice.
+///
+/// # Arguments
+///
+/// * `n` - The number to be formatted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_formatted_number;
+///
+/// assert_eq!(staticize_formatted_number(1_234_567), "1,234,567");
+/// assert_eq!(staticize_formatted_number(-1000), "-1,000");
+/// ```
+pub fn staticize_formatted_number(n: i64) -> &'static str {
+    let digits = n.unsigned_abs().to_string();
+    let mut formatted = String::with_capacity(digits.len() * 4 / 3 + 1);
+    if n < 0 {
+        formatted.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(c);
+    }
+    staticize(formatted)
+}
+
+/// Registers the variant names of a `strum::VariantNames` enum as static strings, and
+/// returns them in declaration order.
+///
+/// The names are already `&'static str`, so they are registered without being copied,
+/// making `is_staticized()` return `true` for them even if they were never staticized.
+/// If an equal string has been staticized before, that one is returned instead.
+///
+/// This function is only available with the `strum` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "strum")]
+/// # {
+/// use static_str_ops::{intern_variant_names, is_staticized};
+///
+/// enum Color {
+///     Red,
+///     Green,
+/// }
+///
+/// impl strum::VariantNames for Color {
+///     const VARIANTS: &'static [&'static str] = &["Red", "Green"];
+/// }
+///
+/// assert_eq!(intern_variant_names::<Color>(), vec!["Red", "Green"]);
+/// assert!(is_staticized("Green"));
+/// # }
+/// ```
+#[cfg(feature = "strum")]
+pub fn intern_variant_names<T: strum::VariantNames>() -> Vec<&'static str> {
+    let mut static_strings = lock_strings();
+    T::VARIANTS
+        .iter()
+        .map(|name| register_static_locked(&mut static_strings, name))
+        .collect()
+}
+
+/// Formats a backtrace and returns the static string slice.
+///
+/// # Arguments
+///
+/// * `bt` - The backtrace to be formatted.
+///
+/// # Examples
+///
+/// ```
+/// use std::backtrace::Backtrace;
+/// use static_str_ops::staticize_backtrace;
+///
+/// let bt = Backtrace::force_capture();
+///
+/// assert_eq!(staticize_backtrace(&bt), bt.to_string());
+/// ```
+pub fn staticize_backtrace(bt: &std::backtrace::Backtrace) -> &'static str {
+    staticize(bt.to_string())
+}
+
+/// Generates a new ULID, and returns its 26-character uppercase representation as a
+/// static string slice.
+///
+/// Every call generates a new ULID, and thus a new static string.
+///
+/// This function is only available with the `ulid` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "ulid")]
+/// # {
+/// use static_str_ops::staticize_ulid;
+///
+/// let request_id = staticize_ulid();
+///
+/// assert_eq!(request_id.len(), 26);
+/// # }
+/// ```
+#[cfg(feature = "ulid")]
+pub fn staticize_ulid() -> &'static str {
+    staticize(ulid::Ulid::generate().to_string())
+}
+
+/// Formats a table header row with fixed column widths, and returns the static string
+/// slice.
+///
+/// Each header is left-aligned and padded with spaces to its width in characters,
+/// columns are separated by a single space. Headers longer than their width are kept
+/// intact.
+///
+/// # Arguments
+///
+/// * `columns` - The headers and their column widths.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_header_row;
+///
+/// let header = staticize_header_row(&[("NAME", 8), ("AGE", 4), ("CITY", 6)]);
+///
+/// assert_eq!(header, "NAME     AGE  CITY  ");
+/// ```
+pub fn staticize_header_row(columns: &[(&str, usize)]) -> &'static str {
+    staticize(
+        columns
+            .iter()
+            .map(|(header, width)| format!("{:<width$}", header, width = width))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Removes all static strings that contain `substring` from the internal set of static
+/// strings.
+///
+/// An empty `substring` removes nothing, rather than every static string.
+///
+/// # Arguments
+///
+/// * `substring` - The substring of the static strings to be removed.
+///
+/// # Returns
+///
+/// The number of removed static strings.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::{evict_containing, staticize};
+///
+/// let _ = staticize("user.session.1");
+/// let _ = staticize("admin.session.2");
+///
+/// assert_eq!(evict_containing(".session."), 2);
+/// ```
+pub fn evict_containing(substring: &str) -> usize {
+    if substring.is_empty() {
+        return 0;
+    }
+    let mut strings = lock_strings();
+    let len = strings.len();
+    strings.retain(|s| {
+        let keep = !s.contains(substring);
+        #[cfg(feature = "audit")]
+        if !keep {
+            record_intern_event(InternEventKind::Evicted, s);
+        }
+        keep
+    });
+    len - strings.len()
+}
+
+/// Converts a protobuf string field value to a static string slice.
+///
+/// This is identical to `staticize()`, the domain-specific name makes interning of
+/// decoded field values easy to find in protobuf-heavy code bases.
+///
+/// # Arguments
+///
+/// * `field` - The string field value.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::intern_proto_field;
+///
+/// let service = String::from("user-service");
+///
+/// assert_eq!(intern_proto_field(&service), "user-service");
+/// ```
+pub fn intern_proto_field(field: &str) -> &'static str {
+    staticize(field)
+}
+
+/// Normalizes line endings to `\n`, and returns the static string slice.
+///
+/// Both `\r\n` and lone `\r` are replaced with `\n`, so strings are staticized
+/// canonically regardless of their line-ending style.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be normalized.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_normalize_newlines;
+///
+/// assert_eq!(staticize_normalize_newlines("a\r\nb\rc\n"), "a\nb\nc\n");
+/// ```
+pub fn staticize_normalize_newlines(s: &str) -> &'static str {
+    staticize(s.replace("\r\n", "\n").replace('\r', "\n"))
+}
+
+/// Converts a string slice to a static string slice, unless it contains `forbidden`.
+///
+/// An empty `forbidden` rejects nothing, rather than every string.
+///
+/// # Arguments
+///
+/// * `s` - A string slice to be converted to a static string slice.
+/// * `forbidden` - The substring that must not appear in `s`.
+///
+/// # Returns
+///
+/// The static string, or `None` if `s` contains `forbidden`, in which case nothing is
+/// added to the internal set.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_unless_contains;
+///
+/// assert_eq!(staticize_unless_contains("alice", ";"), Some("alice"));
+/// assert_eq!(staticize_unless_contains("alice; DROP TABLE", ";"), None);
+/// ```
+pub fn staticize_unless_contains(s: &str, forbidden: &str) -> Option<&'static str> {
+    if !forbidden.is_empty() && s.contains(forbidden) {
+        None
+    } else {
+        Some(staticize(s))
+    }
+}
+
+/// Parses a YAML sequence of strings, and staticizes each of them.
+///
+/// Nothing is added to the internal set if the input is not a valid sequence of strings.
+///
+/// This function is only available with the `yaml` feature.
+///
+/// # Arguments
+///
+/// * `s` - The YAML document.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "yaml")]
+/// # {
+/// use static_str_ops::staticize_yaml_string_seq;
+///
+/// let words = staticize_yaml_string_seq("- apple\n- banana\n").unwrap();
+///
+/// assert_eq!(words, vec!["apple", "banana"]);
+/// # }
+/// ```
+#[cfg(feature = "yaml")]
+pub fn staticize_yaml_string_seq(s: &str) -> Result<Vec<&'static str>, serde_yaml::Error> {
+    let strings: Vec<String> = serde_yaml::from_str(s)?;
+    let mut static_strings = lock_strings();
+    Ok(strings
+        .iter()
+        .map(|s| staticize_locked(&mut static_strings, s))
+        .collect())
+}
+
+/// Wraps a string in ANSI color escape codes, and returns the static string slice.
+///
+/// This function is only available with the `ansi` feature.
+///
+/// # Arguments
+///
+/// * `s` - The string slice to be colored.
+/// * `color` - The SGR color code, e.g., `31` for red foreground.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "ansi")]
+/// # {
+/// use static_str_ops::staticize_colored;
+///
+/// assert_eq!(staticize_colored("ERROR", 31), "\x1b[31mERROR\x1b[0m");
+/// # }
+/// ```
+#[cfg(feature = "ansi")]
+pub fn staticize_colored(s: &str, color: u8) -> &'static str {
+    staticize(format!("\x1b[{}m{}\x1b[0m", color, s))
+}
+
+/// Formats bytes as space-separated 8-digit binary numbers, e.g., `"01000001 01000010"`,
+/// and returns the static string slice.
+///
+/// # Arguments
+///
+/// * `bytes` - The bytes to be formatted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_binary_repr;
+///
+/// assert_eq!(staticize_binary_repr(b"AB"), "01000001 01000010");
+/// ```
+pub fn staticize_binary_repr(bytes: &[u8]) -> &'static str {
+    staticize(
+        bytes
+            .iter()
+            .map(|b| format!("{:08b}", b))
+            .collect::<Vec<_>>()
+            .join(" "),
+    )
+}
+
+/// Initializes an externally managed `OnceCell` with the static string slice of the
+/// string produced by `f`, and returns it.
+///
+/// `f` is called only if the cell is empty. This is the function counterpart of
+/// `staticize_once!()` for when the cell is owned by the caller, e.g., a struct field.
+///
+/// # Arguments
+///
+/// * `cell` - The cell holding the static string slice.
+/// * `f` - Produces the string to be converted when the cell is empty.
+///
+/// # Examples
+///
+/// ```
+/// use std::cell::OnceCell;
+/// use static_str_ops::intern_once_cell;
+///
+/// let cell = OnceCell::new();
+///
+/// assert_eq!(intern_once_cell(&cell, || String::from("computed")), "computed");
+/// assert_eq!(intern_once_cell(&cell, || String::from("ignored")), "computed");
+/// ```
+pub fn intern_once_cell<F: Fn() -> String>(
+    cell: &std::cell::OnceCell<&'static str>,
+    f: F,
+) -> &'static str {
+    cell.get_or_init(|| staticize(f()))
+}
+
+/// Finds the first match of a regex in a text, and returns the matched substring as a
+/// static string slice.
+///
+/// The matched substring is looked up without being copied, so it is allocated only if
+/// it has not been staticized before.
+///
+/// This function is only available with the `regex` feature.
+///
+/// # Arguments
+///
+/// * `text` - The text to search.
+/// * `re` - The regex to search for.
+///
+/// # Returns
+///
+/// The static matched substring, or `None` if `re` does not match.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "regex")]
+/// # {
+/// use regex::Regex;
+/// use static_str_ops::staticize_first_match;
+///
+/// let re = Regex::new(r"\d+\.\d+\.\d+").unwrap();
+///
+/// assert_eq!(staticize_first_match("version 1.2.3 (beta)", &re), Some("1.2.3"));
+/// # }
+/// ```
+#[cfg(feature = "regex")]
+pub fn staticize_first_match(text: &str, re: &regex::Regex) -> Option<&'static str> {
+    let m = re.find(text)?;
+    Some(staticize_locked(&mut lock_strings(), m.as_str()))
+}
+
+/// Staticizes the names and values of all the environment variables of the process.
+///
+/// Variables whose name or value is not valid unicode are skipped. Repeated values are
+/// staticized only once, and the internal lock is acquired only once for all of them.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::intern_full_env;
+///
+/// std::env::set_var("APP_MODE", "production");
+///
+/// assert_eq!(intern_full_env()["APP_MODE"], "production");
+/// ```
+pub fn intern_full_env() -> HashMap<&'static str, &'static str> {
+    let vars: Vec<_> = std::env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+        .collect();
+    let mut static_strings = lock_strings();
+    vars.iter()
+        .map(|(name, value)| {
+            (
+                staticize_locked(&mut static_strings, name),
+                staticize_locked(&mut static_strings, value),
+            )
+        })
+        .collect()
+}
+
+/// Formats items as a bullet list, e.g., `"• item1\n• item2"`, and returns the static
+/// string slice.
+///
+/// # Arguments
+///
+/// * `items` - The items of the list.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_bullet_list;
+///
+/// assert_eq!(staticize_bullet_list(&["fast", "safe"]), "\u{2022} fast\n\u{2022} safe");
+/// ```
+pub fn staticize_bullet_list(items: &[&str]) -> &'static str {
+    staticize(
+        items
+            .iter()
+            .map(|item| format!("\u{2022} {}", item))
+            .collect::<Vec<_>>()
+            .join("\n"),
+    )
+}
+
+/// Converts a number to its English words representation, e.g., `"one hundred
+/// twenty-three"`, and returns the static string slice.
+///
+/// Numbers are written in American style, without `"and"`, and the whole `u32` range
+/// is supported.
+///
+/// # Arguments
+///
+/// * `n` - The number to be converted.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_number_name;
+///
+/// assert_eq!(staticize_number_name(0), "zero");
+/// assert_eq!(staticize_number_name(42), "forty-two");
+/// assert_eq!(staticize_number_name(1_005), "one thousand five");
+/// ```
+pub fn staticize_number_name(n: u32) -> &'static str {
+    const ONES: [&str; 20] = [
+        "zero",
+        "one",
+        "two",
+        "three",
+        "four",
+        "five",
+        "six",
+        "seven",
+        "eight",
+        "nine",
+        "ten",
+        "eleven",
+        "twelve",
+        "thirteen",
+        "fourteen",
+        "fifteen",
+        "sixteen",
+        "seventeen",
+        "eighteen",
+        "nineteen",
+    ];
+    const TENS: [&str; 10] = [
+        "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+    ];
+    const SCALES: [&str; 4] = ["", " thousand", " million", " billion"];
+
+    // names a number below 1000
+    let hundreds = |n: u32| {
+        let mut words = Vec::new();
+        if n >= 100 {
+            words.push(format!("{} hundred", ONES[(n / 100) as usize]));
+        }
+        match n % 100 {
+            0 => {}
+            r @ 1..=19 => words.push(ONES[r as usize].to_string()),
+            r if r % 10 == 0 => words.push(TENS[(r / 10) as usize].to_string()),
+            r => words.push(format!(
+                "{}-{}",
+                TENS[(r / 10) as usize],
+                ONES[(r % 10) as usize]
+            )),
+        }
+        words.join(" ")
+    };
+
+    if n == 0 {
+        return staticize(ONES[0]);
+    }
+    let mut groups = Vec::new();
+    let mut rest = n;
+    for scale in SCALES {
+        let group = rest % 1000;
+        if group != 0 {
+            groups.push(format!("{}{}", hundreds(group), scale));
+        }
+        rest /= 1000;
+    }
+    groups.reverse();
+    staticize(groups.join(" "))
+}
+
+/// Formats a Unix timestamp in milliseconds as an ISO 8601 UTC date-time, e.g.,
+/// `"2024-02-29T12:34:56.789Z"`, and returns the static string slice.
+///
+/// The calendar date is computed with plain integer arithmetic, without depending on a
+/// date-time library.
+///
+/// # Arguments
+///
+/// * `ms` - The number of milliseconds since the Unix epoch.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::staticize_unix_millis;
+///
+/// assert_eq!(staticize_unix_millis(0), "1970-01-01T00:00:00.000Z");
+/// assert_eq!(staticize_unix_millis(1_709_210_096_789), "2024-02-29T12:34:56.789Z");
+/// ```
+pub fn staticize_unix_millis(ms: u64) -> &'static str {
+    let (days, ms_of_day) = (ms / 86_400_000, ms % 86_400_000);
+
+    // civil date from days since 1970-01-01, in 400-year eras starting on 0000-03-01
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    staticize(format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}.{:03}Z",
+        year,
+        month,
+        day,
+        ms_of_day / 3_600_000,
+        ms_of_day / 60_000 % 60,
+        ms_of_day / 1000 % 60,
+        ms_of_day % 1000
+    ))
+}
+
+/// Formats a semantic version, including its pre-release and build metadata, and
+/// returns the static string slice.
+///
+/// Empty `pre` or `build` are omitted from the version string.
+///
+/// This function is only available with the `semver` feature.
+///
+/// # Arguments
+///
+/// * `major` - The major version.
+/// * `minor` - The minor version.
+/// * `patch` - The patch version.
+/// * `pre` - The pre-release identifiers, e.g., `"rc.1"`.
+/// * `build` - The build metadata, e.g., `"git.abc123"`.
+///
+/// # Returns
+///
+/// The static version string, or the `semver::Error` if `pre` or `build` are invalid.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "semver")]
+/// # {
+/// use static_str_ops::staticize_semver;
+///
+/// assert_eq!(staticize_semver(1, 2, 3, "rc.1", "git.abc123").unwrap(), "1.2.3-rc.1+git.abc123");
+/// assert_eq!(staticize_semver(1, 2, 3, "", "").unwrap(), "1.2.3");
+/// # }
+/// ```
+#[cfg(feature = "semver")]
+pub fn staticize_semver(
+    major: u32,
+    minor: u32,
+    patch: u32,
+    pre: &str,
+    build: &str,
+) -> Result<&'static str, semver::Error> {
+    let version = semver::Version {
+        major: major.into(),
+        minor: minor.into(),
+        patch: patch.into(),
+        pre: semver::Prerelease::new(pre)?,
+        build: semver::BuildMetadata::new(build)?,
+    };
+    Ok(staticize(version.to_string()))
+}
+
+/// Concatenates the given string literals into a single static string slice.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::static_concat;
+///
+/// let hello_world: &'static str = static_concat!("Hello", ", ", "world!");
+/// assert_eq!(hello_world, "Hello, world!");
+/// ```
+///
+/// # Panics
+///
+/// This macro will panic if any of the input expressions is not a string literal.
+#[macro_export]
+macro_rules! static_concat {
+    ()=>{""};
+    ($($arg: expr),* $(,)?)=>(
+        $crate::staticize(concat!($($arg),*))
+    );
+}
+
+/// A macro that takes a format string and arguments, and returns a static string slice.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::static_format;
+///
+/// let name = "John";
+/// let age = 30;
+/// let message = static_format!("My name is {} and I'm {} years old.", name, age);
+///
+/// assert_eq!(message, "My name is John and I'm 30 years old.");
+/// ```
+///
+/// This macro is similar to the `format!` macro, but it returns a static string slice
+/// instead of a `String`, and can take whatever the `format!` macro recognizes.
+#[macro_export]
+macro_rules! static_format {
+    ()=>{""};
+    ($($arg: expr),* $(,)?)=>(
+        $crate::staticize(format!($($arg),*))
+    );
+}
+
+/// Internally used by `staticize_once!()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _staticize_once {
+    ($gensym: ident, $expr: expr) => {{
+        use std::sync::Once;
+
+        static mut $gensym: &str = "";
+        static INIT: Once = Once::new();
+        INIT.call_once(|| unsafe {
+            $gensym = staticize($expr);
+        });
+        unsafe { $gensym }
+    }};
+}
+
+/// Macro to generate a unique identifier for a given expression, which can be used to create a static variable
+/// that is initialized once with the result of the expression. This macro ensures that the expression is only
+/// evaluated once at runtime, and the result is stored in a static variable for future use.
+///
+/// # Arguments
+///
+/// * `expr` - An expression of type `&str` or `String`.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(stmt_expr_attributes)]
+///
+/// use static_str_ops::*;
+///
+/// let s = "hello world";
+/// let static_str = staticize_once!(s.to_uppercase());
+/// assert_eq!(static_str, "HELLO WORLD");
+/// ```
+///
+/// # Notes
+///
+/// This macro uses the `gensym` crate to generate a unique identifier for the expression. The `gensym` crate
+/// is a compile-time code generation library that generates unique identifiers based on the source code
+/// location where the macro is invoked. This ensures that the identifier is unique across the entire crate,
+/// and is not accidentally used elsewhere.
+#[macro_export]
 macro_rules! staticize_once {
     ($expr: expr) => {
         gensym::gensym! { _staticize_once!{ $expr } }
     };
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::sync::atomic;
+/// Returns the type names of the given types, e.g., of all the components of a tuple
+/// type, as static string slices.
+///
+/// # Examples
+///
+/// ```
+/// use static_str_ops::intern_type_names_for_tuple;
+///
+/// let names: Vec<&'static str> = intern_type_names_for_tuple!(i32, bool, &str);
+///
+/// assert_eq!(names, vec!["i32", "bool", "&str"]);
+/// ```
+#[macro_export]
+macro_rules! intern_type_names_for_tuple {
+    ($($ty: ty),* $(,)?) => {
+        vec![$($crate::staticize(::std::any::type_name::<$ty>())),*]
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic;
+
+    #[test]
+    fn test_staticize() {
+        let s = staticize("hello");
+        assert_eq!(s, "hello");
+        let s = staticize("world");
+        assert_eq!(s, "world");
+
+        let s = staticize(String::from("hello world!"));
+        assert_eq!(s, "hello world!");
+    }
+
+    #[test]
+    fn test_is_staticized() {
+        let s = "new hello world!";
+        assert!(!is_staticized(s));
+        let _ = staticize(s);
+        assert!(is_staticized(s));
+    }
+
+    #[test]
+    fn test_destaticize() {
+        let s = "new hello world to be destaticized!";
+        assert!(!is_staticized(s));
+        let _ = staticize(s);
+        assert!(is_staticized(s));
+        assert!(destaticize(s));
+        println!("{:?}", lock_strings());
+        assert!(!is_staticized(s));
+    }
+
+    #[test]
+    fn test_static_concat() {
+        let result: &'static str = static_concat!("hello", " ", "world", "!");
+        assert_eq!(result, "hello world!");
+    }
+
+    #[test]
+    fn test_static_format() {
+        let result: &'static str = static_format!("{} {}!", "hello", "world");
+        assert_eq!(result, "hello world!");
+    }
+
+    trait Typename {
+        fn typename() -> &'static str {
+            std::any::type_name::<Self>()
+        }
+    }
+
+    impl Typename for i32 {}
+    impl Typename for i64 {}
+
+    struct Data<T> {
+        phantom: std::marker::PhantomData<T>,
+    }
+
+    impl<T: Typename> Typename for Data<T> {
+        fn typename() -> &'static str {
+            static_format!("Data<{}>", T::typename())
+        }
+    }
+
+    #[test]
+    fn test_typename() {
+        assert_eq!(Data::<i32>::typename(), "Data<i32>");
+        assert_eq!(Data::<Data::<i64>>::typename(), "Data<Data<i64>>");
+    }
+
+    #[test]
+    fn tets_staticize_once() {
+        let called = atomic::AtomicI32::new(0);
+
+        let make_typename = || {
+            staticize_once!({
+                called.fetch_add(1, atomic::Ordering::SeqCst);
+                Data::<i32>::typename()
+            })
+        };
+
+        let s1: &'static str = make_typename();
+        let s2: &'static str = make_typename();
+        let s3: &'static str = make_typename();
+        assert!(s1 == s2 && s2 == s3);
+
+        // ensure the body been called only once
+        assert!(called.load(atomic::Ordering::SeqCst) == 1);
+    }
+
+    #[test]
+    fn test_staticize_repeat_join() {
+        assert_eq!(staticize_repeat_join("?", 5, ", "), "?, ?, ?, ?, ?");
+        assert_eq!(staticize_repeat_join("?", 1, ", "), "?");
+        assert_eq!(staticize_repeat_join("?", 0, ", "), "");
+    }
+
+    #[test]
+    fn test_staticize_rs_delimited() {
+        let fields = staticize_rs_delimited(b"ISA\x1eGS\x1e\x1eST").unwrap();
+        assert_eq!(fields, vec!["ISA", "GS", "", "ST"]);
+
+        let s = "rs delimited field before invalid utf-8";
+        let mut data = s.as_bytes().to_vec();
+        data.extend_from_slice(b"\x1e\xff");
+        assert!(staticize_rs_delimited(&data).is_err());
+        assert!(!is_staticized(s));
+    }
+
+    #[test]
+    fn test_staticize_fixed_width() {
+        assert_eq!(staticize_fixed_width("hello", 8), "hello   ");
+        assert_eq!(staticize_fixed_width("hello world", 5), "hello");
+        assert_eq!(staticize_fixed_width("hello", 5), "hello");
+        assert_eq!(staticize_fixed_width("hello", 0), "");
+
+        // "é" takes two bytes, the truncation must not split it
+        let s = staticize_fixed_width("caf\u{e9}!", 4);
+        assert_eq!(s, "caf ");
+        assert_eq!(s.len(), 4);
+    }
+
+    #[test]
+    fn test_all_staticized_between_lengths() {
+        let short = staticize("between lengths: short");
+        let long = staticize("between lengths: a bit longer");
+
+        let strings = all_staticized_between_lengths(short.len(), short.len());
+        assert!(strings.contains(&short));
+        assert!(!strings.contains(&long));
+        assert!(strings.iter().all(|s| s.len() == short.len()));
+
+        let strings = all_staticized_between_lengths(short.len(), long.len());
+        assert!(strings.contains(&short) && strings.contains(&long));
+
+        assert!(all_staticized_between_lengths(1, 0).is_empty());
+    }
+
+    #[test]
+    fn test_staticize_table() {
+        let table = staticize_table(&["name", "age"], &[vec!["alice", "30"], vec!["bob", "4"]]);
+        assert_eq!(table, "name  | age\n------+----\nalice | 30\nbob   | 4");
+
+        let table = staticize_table(&["k", "v"], &[vec!["key"], vec!["a", "b", "extra"]]);
+        assert_eq!(table, "k   | v\n----+---+------\nkey\na   | b | extra");
+
+        let table = staticize_table(&["k", "v"], &[vec!["x", "", "z"], vec!["", "y "]]);
+        assert_eq!(table, "k | v\n--+----+--\nx |    | z\n  | y ");
+
+        assert_eq!(staticize_table(&[], &[]), "");
+    }
+
+    #[test]
+    fn test_staticize_kv_pairs() {
+        let labels = [("env", "prod"), ("region", "us-east")];
+        assert_eq!(
+            staticize_kv_pairs(labels.into_iter(), ';', '='),
+            "env=prod;region=us-east"
+        );
+        assert_eq!(staticize_kv_pairs([("a", "")].into_iter(), ';', ':'), "a:");
+        assert_eq!(staticize_kv_pairs(std::iter::empty(), ';', '='), "");
+    }
+
+    #[test]
+    fn test_is_subset_interned() {
+        let a = staticize("subset interned a");
+        let b = staticize("subset interned b");
+        assert!(is_subset_interned(&[a, b]));
+        assert!(is_subset_interned(&[]));
+        assert!(!is_subset_interned(&[
+            a,
+            "subset interned never staticized"
+        ]));
+    }
 
     #[test]
-    fn test_staticize() {
-        let s = staticize("hello");
-        assert_eq!(s, "hello");
-        let s = staticize("world");
-        assert_eq!(s, "world");
+    fn test_staticize_deduplicated() {
+        let names = staticize_deduplicated(&["Accept", "Host", "Accept", "Cookie", "Host"]);
+        assert_eq!(names, vec!["Accept", "Host", "Cookie"]);
+        assert!(names.iter().all(|s| is_staticized(s)));
+        assert_eq!(names[0].as_ptr(), staticize("Accept").as_ptr());
 
-        let s = staticize(String::from("hello world!"));
-        assert_eq!(s, "hello world!");
+        assert!(staticize_deduplicated(&[]).is_empty());
     }
 
     #[test]
-    fn test_is_staticized() {
-        let s = "new hello world!";
-        assert!(!is_staticized(s));
-        let _ = staticize(s);
+    fn test_staticize_sorted() {
+        let labels = staticize_sorted(&["region", "env", "host", "env"]);
+        assert_eq!(labels, vec!["env", "env", "host", "region"]);
+        assert_eq!(labels[0].as_ptr(), labels[1].as_ptr());
+        assert!(is_subset_interned(&labels));
+    }
+
+    #[test]
+    fn test_staticize_with_char_count() {
+        let (s, count) = staticize_with_char_count("caf\u{e9}");
+        assert_eq!(s, "caf\u{e9}");
+        assert_eq!(s.len(), 5);
+        assert_eq!(count, 4);
         assert!(is_staticized(s));
+        assert_eq!(staticize_with_char_count(""), ("", 0));
     }
 
     #[test]
-    fn test_destaticize() {
-        let s = "new hello world to be destaticized!";
-        assert!(!is_staticized(s));
-        let _ = staticize(s);
+    fn test_destaticize_set() {
+        let a = staticize("destaticize set a");
+        let b = staticize("destaticize set b");
+        let to_remove: HashSet<&str> = [a, b, "destaticize set never staticized"]
+            .into_iter()
+            .collect();
+        assert_eq!(destaticize_set(&to_remove), 2);
+        assert!(!is_staticized(a) && !is_staticized(b));
+        assert_eq!(destaticize_set(&to_remove), 0);
+    }
+
+    #[test]
+    fn test_staticize_chunks() {
+        assert_eq!(
+            staticize_chunks("hello world", 4),
+            vec!["hell", "o wo", "rld"]
+        );
+        assert_eq!(staticize_chunks("abc", 3), vec!["abc"]);
+        assert!(staticize_chunks("", 3).is_empty());
+
+        // "é" takes two bytes and must not be split
+        assert_eq!(staticize_chunks("caf\u{e9}s", 4), vec!["caf", "\u{e9}s"]);
+        assert_eq!(
+            staticize_chunks("\u{e9}\u{e9}", 1),
+            vec!["\u{e9}", "\u{e9}"]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_staticize_chunks_zero_size() {
+        staticize_chunks("hello", 0);
+    }
+
+    #[test]
+    fn test_staticize_binary_size() {
+        assert_eq!(staticize_binary_size(0), "0 b");
+        assert_eq!(staticize_binary_size(1023), "1023 b");
+        assert_eq!(staticize_binary_size(1024), "1.00 Kib");
+        assert_eq!(staticize_binary_size(1536), "1.50 Kib");
+        assert_eq!(staticize_binary_size((1 << 20) - 1), "1.00 Mib");
+        assert_eq!(staticize_binary_size(1 << 20), "1.00 Mib");
+        assert_eq!(staticize_binary_size((1 << 30) - 1), "1.00 Gib");
+        assert_eq!(staticize_binary_size(3 << 30), "3.00 Gib");
+        assert_eq!(staticize_binary_size(1 << 40), "1.00 Tib");
+        assert_eq!(staticize_binary_size(2048 << 40), "2048.00 Tib");
+    }
+
+    #[test]
+    fn test_staticize_roman() {
+        assert_eq!(staticize_roman(1), Some("I"));
+        assert_eq!(staticize_roman(4), Some("IV"));
+        assert_eq!(staticize_roman(14), Some("XIV"));
+        assert_eq!(staticize_roman(1994), Some("MCMXCIV"));
+        assert_eq!(staticize_roman(3999), Some("MMMCMXCIX"));
+        assert_eq!(staticize_roman(0), None);
+        assert_eq!(staticize_roman(4000), None);
+    }
+
+    #[test]
+    fn test_staticize_ordinal() {
+        assert_eq!(staticize_ordinal(0), "0th");
+        assert_eq!(staticize_ordinal(1), "1st");
+        assert_eq!(staticize_ordinal(2), "2nd");
+        assert_eq!(staticize_ordinal(3), "3rd");
+        assert_eq!(staticize_ordinal(4), "4th");
+        assert_eq!(staticize_ordinal(11), "11th");
+        assert_eq!(staticize_ordinal(12), "12th");
+        assert_eq!(staticize_ordinal(13), "13th");
+        assert_eq!(staticize_ordinal(21), "21st");
+        assert_eq!(staticize_ordinal(111), "111th");
+        assert_eq!(staticize_ordinal(102), "102nd");
+    }
+
+    #[cfg(feature = "bincode")]
+    #[test]
+    fn test_staticize_bincode() {
+        let bytes = bincode::serialize("hello bincode").unwrap();
+        let s = staticize_bincode(&bytes).unwrap();
+        assert_eq!(s, "hello bincode");
+        assert!(is_staticized(s));
+
+        assert!(staticize_bincode(&bytes[..4]).is_err());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn test_staticize_span_name() {
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        #[derive(Clone, Default)]
+        struct Recorder {
+            callsites: std::sync::Arc<Mutex<Vec<&'static str>>>,
+            spans: std::sync::Arc<Mutex<Vec<&'static str>>>,
+        }
+
+        impl Subscriber for Recorder {
+            fn register_callsite(
+                &self,
+                metadata: &'static Metadata<'static>,
+            ) -> tracing::subscriber::Interest {
+                self.callsites.lock().unwrap().push(metadata.name());
+                tracing::subscriber::Interest::always()
+            }
+            fn enabled(&self, _: &Metadata<'_>) -> bool {
+                true
+            }
+            fn new_span(&self, span: &Attributes<'_>) -> Id {
+                let mut spans = self.spans.lock().unwrap();
+                spans.push(span.metadata().name());
+                Id::from_u64(spans.len() as u64)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {}
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let recorder = Recorder::default();
+        let subscriber = recorder.clone();
+        tracing::subscriber::with_default(subscriber, || {
+            let name = format!("span_{}", "handle_request");
+            let metadata =
+                staticize_span_name(&name, "static_str_ops::tests", tracing::Level::INFO);
+            assert_eq!(metadata.name(), "span_handle_request");
+            assert_eq!(metadata.target(), "static_str_ops::tests");
+            assert_eq!(*metadata.level(), tracing::Level::INFO);
+            assert!(metadata.is_span());
+            assert!(is_staticized(metadata.name()));
+
+            let again = staticize_span_name(&name, "static_str_ops::tests", tracing::Level::INFO);
+            assert!(std::ptr::eq(metadata, again));
+            let other = staticize_span_name(&name, "static_str_ops::tests", tracing::Level::DEBUG);
+            assert!(!std::ptr::eq(metadata, other));
+
+            let _span = tracing::Span::new(metadata, &metadata.fields().value_set(&[]));
+        });
+
+        let callsites = recorder.callsites.lock().unwrap();
+        assert_eq!(
+            callsites
+                .iter()
+                .filter(|n| **n == "span_handle_request")
+                .count(),
+            2
+        );
+        assert_eq!(*recorder.spans.lock().unwrap(), vec!["span_handle_request"]);
+    }
+
+    #[test]
+    fn test_staticize_word_wrap() {
+        assert_eq!(
+            staticize_word_wrap("the quick brown fox jumps", 10),
+            vec!["the quick", "brown fox", "jumps"]
+        );
+        assert_eq!(
+            staticize_word_wrap("  a   verylongword b\nc ", 4),
+            vec!["a", "verylongword", "b", "c"]
+        );
+        assert_eq!(
+            staticize_word_wrap("para one\n\npara two", 80),
+            vec!["para one", "", "para two"]
+        );
+        assert_eq!(
+            staticize_word_wrap("caf\u{e9} ol\u{e9}", 8),
+            vec!["caf\u{e9} ol\u{e9}"]
+        );
+        assert_eq!(staticize_word_wrap("   ", 10), vec![""]);
+        assert!(staticize_word_wrap("", 10).is_empty());
+    }
+
+    #[test]
+    fn test_all_staticized_ascii() {
+        let ascii = staticize("all staticized ascii");
+        let non_ascii = staticize("all staticized non-ascii \u{e9}");
+
+        let strings = all_staticized_ascii();
+        assert!(strings.contains(&ascii) && !strings.contains(&non_ascii));
+        assert!(strings.iter().all(|s| s.is_ascii()));
+
+        let strings = all_staticized_non_ascii();
+        assert!(!strings.contains(&ascii) && strings.contains(&non_ascii));
+        assert!(strings.iter().all(|s| !s.is_ascii()));
+    }
+
+    #[cfg(feature = "prost")]
+    #[test]
+    fn test_staticize_proto_string() {
+        let bytes = prost::Message::encode_to_vec(&String::from("hello proto"));
+        let s = staticize_proto_string(&bytes).unwrap();
+        assert_eq!(s, "hello proto");
+        assert!(is_staticized(s));
+
+        // truncated payload
+        assert!(staticize_proto_string(&bytes[..bytes.len() - 1]).is_err());
+
+        // trailing fields are decoded too: field 2 is skipped, field 1 keeps the last value
+        assert_eq!(
+            staticize_proto_string(b"\x0a\x02ab\x12\x01x\x0a\x02cd").unwrap(),
+            "cd"
+        );
+        // trailing garbage is an error
+        assert!(staticize_proto_string(b"\x0a\x02ab\xff").is_err());
+
+        // field 1 as a varint, wire type 0
+        assert!(staticize_proto_string(b"\x08\x2a").is_err());
+
+        // empty input
+        assert_eq!(staticize_proto_string(b"").unwrap(), "");
+
+        // invalid utf-8
+        assert!(staticize_proto_string(b"\x0a\x01\xff").is_err());
+    }
+
+    #[cfg(feature = "markdown")]
+    #[test]
+    fn test_staticize_markdown_escaped() {
+        assert_eq!(
+            staticize_markdown_escaped("*bold* _it_ `code` [a](b)"),
+            r"\*bold\* \_it\_ \`code\` \[a\]\(b\)"
+        );
+        assert_eq!(staticize_markdown_escaped(r"a\b"), r"a\\b");
+        assert_eq!(staticize_markdown_escaped("plain text"), "plain text");
+    }
+
+    #[test]
+    fn test_staticize_with_fallback() {
+        assert_eq!(
+            staticize_with_fallback("", || String::from("fallback value")),
+            "fallback value"
+        );
+        assert_eq!(
+            staticize_with_fallback("configured value", || unreachable!()),
+            "configured value"
+        );
+    }
+
+    #[test]
+    fn test_evict_prefixes() {
+        let a = staticize("evict prefixes/a/1");
+        let b = staticize("evict prefixes/a/2");
+        let c = staticize("evict prefixes/b/1");
+        let d = staticize("evict prefixes/c/1");
+
+        let removed = evict_prefixes(&["evict prefixes/a/", "evict prefixes/", "evict x"]);
+        assert_eq!(removed.len(), 3);
+        assert_eq!(removed["evict prefixes/a/"], 2);
+        assert_eq!(removed["evict prefixes/"], 2);
+        assert_eq!(removed["evict x"], 0);
+        assert!(!is_staticized(a) && !is_staticized(b));
+        assert!(!is_staticized(c) && !is_staticized(d));
+
+        let e = staticize("evict prefixes/e/1");
+        let removed = evict_prefixes(&[""]);
+        assert_eq!(removed[""], 0);
+        assert!(is_staticized(e));
+    }
+
+    #[test]
+    fn test_staticize_utf8() {
+        let s = staticize_utf8("utf-8 caf\u{e9}".as_bytes()).unwrap();
+        assert_eq!(s, "utf-8 caf\u{e9}");
+        assert!(is_staticized(s));
+        assert!(staticize_utf8(b"utf-8 \xff").is_err());
+        assert!(!is_staticized("utf-8 \u{fffd}"));
+    }
+
+    #[test]
+    fn test_staticize_hash() {
+        let key = staticize_hash(&(42, "answer"));
+        assert!(key.starts_with("0x") && key.len() == 18);
+        assert!(key[2..].chars().all(|c| matches!(c, '0'..='9' | 'A'..='F')));
+        assert_eq!(key.as_ptr(), staticize_hash(&(42, "answer")).as_ptr());
+        assert_ne!(key, staticize_hash(&(43, "answer")));
+    }
+
+    #[test]
+    fn test_staticize_conditional() {
+        assert_eq!(
+            staticize_conditional(true, "cond on", "cond off"),
+            "cond on"
+        );
+        assert_eq!(
+            staticize_conditional(false, "cond on", "cond off"),
+            "cond off"
+        );
+    }
+
+    #[test]
+    fn test_intern_map_entry() {
+        let mut names = HashMap::new();
+        names.insert("b", String::from("map entry existing"));
+
+        let s = intern_map_entry(&mut names, "a", || String::from("map entry default"));
+        assert_eq!(s, "map entry default");
+        assert!(is_staticized(s));
+        assert_eq!(names["a"], "map entry default");
+
+        let s = intern_map_entry(&mut names, "b", || unreachable!());
+        assert_eq!(s, "map entry existing");
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn test_staticize_kv() {
+        assert_eq!(staticize_kv("user_id", "123"), "user_id=123");
+        assert_eq!(staticize_kv("empty", ""), "empty=");
+    }
+
+    #[test]
+    fn test_staticize_json_string_literal() {
+        assert_eq!(staticize_json_string_literal(""), r#""""#);
+        assert_eq!(staticize_json_string_literal("a\"b\\c/d"), r#""a\"b\\c/d""#);
+        assert_eq!(
+            staticize_json_string_literal("\n\r\t\u{08}\u{0c}\u{01}\u{1f}"),
+            r#""\n\r\t\b\f\u0001\u001f""#
+        );
+        assert_eq!(staticize_json_string_literal("caf\u{e9}"), "\"caf\u{e9}\"");
+    }
+
+    #[test]
+    fn test_staticize_from_reader_line_by_line() {
+        let input = "alpha\n\nbeta\r\ngamma\n";
+        let lines = staticize_from_reader_line_by_line(input.as_bytes(), 3).unwrap();
+        assert_eq!(lines, vec!["alpha", "beta"]);
+        let lines = staticize_from_reader_line_by_line(input.as_bytes(), 100).unwrap();
+        assert_eq!(lines, vec!["alpha", "beta", "gamma"]);
+        let lines = staticize_from_reader_line_by_line(input.as_bytes(), 0).unwrap();
+        assert!(lines.is_empty());
+
+        let invalid: &[u8] = b"reader line\n\xff\n";
+        assert!(staticize_from_reader_line_by_line(invalid, 10).is_err());
+    }
+
+    #[test]
+    fn test_staticize_split_once() {
+        let (name, value) = staticize_split_once("X-Split-Once: a:b", ':').unwrap();
+        assert_eq!((name, value), ("X-Split-Once", " a:b"));
+        assert!(is_subset_interned(&[name, value]));
+        assert_eq!(staticize_split_once("key=", '='), Some(("key", "")));
+        assert_eq!(staticize_split_once("no delimiter", ':'), None);
+    }
+
+    #[test]
+    fn test_staticize_prefixed_new() {
+        let (s, is_new) = staticize_prefixed_new("prefixed new.", "first");
+        assert_eq!(s, "prefixed new.first");
+        assert!(is_new && is_staticized(s));
+
+        let (again, is_new) = staticize_prefixed_new("prefixed new.", "first");
+        assert_eq!(again.as_ptr(), s.as_ptr());
+        assert!(!is_new);
+    }
+
+    #[test]
+    fn test_with_staticized() {
+        let s = staticize("with staticized");
+        assert!(with_staticized(|strings| strings.contains(s)));
+        let longest = with_staticized(|strings| strings.iter().map(|s| s.len()).max());
+        assert!(longest.unwrap() >= s.len());
+    }
+
+    #[test]
+    fn test_with_staticized_panic() {
+        let result = std::panic::catch_unwind(|| {
+            with_staticized(|_| panic!("panic inside with_staticized"));
+        });
+        assert!(result.is_err());
+
+        let s = staticize("after with_staticized panic");
         assert!(is_staticized(s));
         assert!(destaticize(s));
-        println!("{:?}", STATIC_STRINGS.lock().unwrap());
-        assert!(!is_staticized(s));
     }
 
     #[test]
-    fn test_static_concat() {
-        let result: &'static str = static_concat!("hello", " ", "world", "!");
-        assert_eq!(result, "hello world!");
+    fn test_staticize_duration_secs() {
+        assert_eq!(staticize_duration_secs(0.0), "0.0s");
+        assert_eq!(staticize_duration_secs(3.44), "3.4s");
+        assert_eq!(staticize_duration_secs(59.96), "1m 0.0s");
+        assert_eq!(staticize_duration_secs(62.5), "1m 2.5s");
+        assert_eq!(staticize_duration_secs(3600.0), "1h 0m 0.0s");
+        assert_eq!(staticize_duration_secs(3723.4), "1h 2m 3.4s");
+        assert_eq!(staticize_duration_secs(90000.0), "25h 0m 0.0s");
+        assert_eq!(staticize_duration_secs(-62.5), "-1m 2.5s");
+        assert_eq!(staticize_duration_secs(-0.01), "0.0s");
+        assert_eq!(staticize_duration_secs(-0.05), "-0.1s");
+        assert_eq!(
+            staticize_duration_secs(1e30),
+            "1000000000000000000000000000000s"
+        );
+        assert_eq!(
+            staticize_duration_secs(-1e30),
+            "-1000000000000000000000000000000s"
+        );
+        assert_eq!(staticize_duration_secs(f64::INFINITY), "infs");
     }
 
     #[test]
-    fn test_static_format() {
-        let result: &'static str = static_format!("{} {}!", "hello", "world");
-        assert_eq!(result, "hello world!");
+    fn test_staticize_percentage() {
+        assert_eq!(staticize_percentage(17.0, 40.0), "42.50%");
+        assert_eq!(staticize_percentage(1.0, 3.0), "33.33%");
+        assert_eq!(staticize_percentage(0.0, 5.0), "0.00%");
+        assert_eq!(staticize_percentage(5.0, 4.0), "125.00%");
     }
 
-    trait Typename {
-        fn typename() -> &'static str {
-            std::any::type_name::<Self>()
+    #[cfg(feature = "audit")]
+    #[test]
+    fn test_intern_event_log() {
+        let s = staticize("intern event log");
+        let _ = staticize_deduplicated(&["intern event log"]);
+        assert!(destaticize(s));
+
+        let kinds: Vec<_> = intern_event_log(INTERN_EVENT_LOG_CAPACITY)
+            .into_iter()
+            .filter(|e| e.string == "intern event log")
+            .map(|e| e.kind)
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                InternEventKind::New,
+                InternEventKind::Hit,
+                InternEventKind::Evicted
+            ]
+        );
+
+        assert!(intern_event_log(0).is_empty());
+        let events = intern_event_log(2);
+        assert!(events.len() <= 2);
+        assert!(events.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+    }
+
+    #[test]
+    fn test_staticize_startup() {
+        let s = staticize_startup("startup string");
+        assert_eq!(s, "startup string");
+        assert!(is_staticized(s));
+        assert_eq!(staticize_startup("startup string").as_ptr(), s.as_ptr());
+        assert_eq!(staticize("startup string").as_ptr(), s.as_ptr());
+    }
+
+    #[test]
+    fn test_staticize_env_var_split() {
+        std::env::set_var("STATIC_STR_OPS_TEST_SPLIT", "/a/b;/c;;/d");
+        assert_eq!(
+            staticize_env_var_split("STATIC_STR_OPS_TEST_SPLIT", ';'),
+            Some(vec!["/a/b", "/c", "", "/d"])
+        );
+        assert_eq!(
+            staticize_env_var_split("STATIC_STR_OPS_TEST_UNSET", ';'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_staticize_hex_dump() {
+        assert_eq!(staticize_hex_dump(&[0xde, 0xad, 0xbe, 0xef]), "DE AD BE EF");
+        assert_eq!(staticize_hex_dump(&[0x00, 0x0a]), "00 0A");
+        assert_eq!(staticize_hex_dump(&[]), "");
+    }
+
+    #[test]
+    fn test_staticize_mac_addr() {
+        assert_eq!(
+            staticize_mac_addr(&[0x00, 0x1a, 0x2b, 0x3c, 0x4d, 0x5e]),
+            "00:1A:2B:3C:4D:5E"
+        );
+        assert_eq!(staticize_mac_addr(&[0xff; 6]), "FF:FF:FF:FF:FF:FF");
+    }
+
+    #[test]
+    fn test_intern_short_type_name() {
+        assert_eq!(intern_short_type_name::<i32>(), "i32");
+        assert_eq!(intern_short_type_name::<String>(), "String");
+        assert_eq!(intern_short_type_name::<Data<i64>>(), "Data<i64>");
+        assert_eq!(
+            intern_short_type_name::<HashMap<String, Vec<u8>>>(),
+            "HashMap<String, Vec<u8>>"
+        );
+        assert_eq!(
+            intern_short_type_name::<(std::sync::Mutex<()>, [u8; 4])>(),
+            "(Mutex<()>, [u8; 4])"
+        );
+        assert_eq!(intern_short_type_name::<dyn std::fmt::Debug>(), "dyn Debug");
+    }
+
+    #[test]
+    fn test_staticize_display_and_debug() {
+        let err = "x".parse::<i32>().unwrap_err();
+        let (display, debug) = staticize_display_and_debug(&err);
+        assert_eq!(display, "invalid digit found in string");
+        assert_eq!(debug, "ParseIntError { kind: InvalidDigit }");
+        assert!(is_subset_interned(&[display, debug]));
+    }
+
+    #[test]
+    fn test_intern_array() {
+        let owned = String::from("array b");
+        let labels = intern_array(["array a", &owned, "array a"]);
+        assert_eq!(labels, ["array a", "array b", "array a"]);
+        assert_eq!(labels[0].as_ptr(), labels[2].as_ptr());
+        assert!(is_subset_interned(&labels));
+        assert_eq!(intern_array([]), [""; 0]);
+    }
+
+    #[test]
+    fn test_staticize_path_components() {
+        use std::path::Path;
+
+        assert_eq!(
+            staticize_path_components(Path::new("a/./b//../c.txt")),
+            vec!["a", "b", "..", "c.txt"]
+        );
+        assert!(staticize_path_components(Path::new("")).is_empty());
+
+        #[cfg(unix)]
+        {
+            use std::ffi::OsStr;
+            use std::os::unix::ffi::OsStrExt;
+
+            assert_eq!(
+                staticize_path_components(Path::new("/usr")),
+                vec!["/", "usr"]
+            );
+            let path = Path::new(OsStr::from_bytes(b"dir/\xff/file"));
+            assert_eq!(staticize_path_components(path), vec!["dir", "file"]);
         }
     }
 
-    impl Typename for i32 {}
-    impl Typename for i64 {}
+    #[cfg(feature = "compact_str")]
+    #[test]
+    fn test_staticize_compact() {
+        use compact_str::CompactString;
 
-    struct Data<T> {
-        phantom: std::marker::PhantomData<T>,
+        let inline = CompactString::new("compact inline");
+        assert!(!inline.is_heap_allocated());
+        let s = staticize_compact(inline);
+        assert_eq!(s, "compact inline");
+        assert_eq!(staticize("compact inline").as_ptr(), s.as_ptr());
+
+        let heap = CompactString::new("compact string that does not fit inline");
+        assert!(heap.is_heap_allocated());
+        let s = staticize_compact(heap);
+        assert_eq!(s, "compact string that does not fit inline");
+        assert!(is_staticized(s));
     }
 
-    impl<T: Typename> Typename for Data<T> {
-        fn typename() -> &'static str {
-            static_format!("Data<{}>", T::typename())
+    #[test]
+    fn test_staticize_formatted_number() {
+        assert_eq!(staticize_formatted_number(0), "0");
+        assert_eq!(staticize_formatted_number(999), "999");
+        assert_eq!(staticize_formatted_number(1000), "1,000");
+        assert_eq!(staticize_formatted_number(1_234_567), "1,234,567");
+        assert_eq!(staticize_formatted_number(-123_456), "-123,456");
+        assert_eq!(
+            staticize_formatted_number(i64::MIN),
+            "-9,223,372,036,854,775,808"
+        );
+    }
+
+    #[cfg(feature = "strum")]
+    #[test]
+    fn test_intern_variant_names() {
+        #[allow(dead_code)]
+        enum Variants {
+            Registered,
+            Existing,
         }
+
+        impl strum::VariantNames for Variants {
+            const VARIANTS: &'static [&'static str] = &["VariantRegistered", "VariantExisting"];
+        }
+
+        let existing = staticize(String::from("VariantExisting"));
+        let names = intern_variant_names::<Variants>();
+        assert_eq!(names, vec!["VariantRegistered", "VariantExisting"]);
+        assert_eq!(names[1].as_ptr(), existing.as_ptr());
+        assert!(is_staticized("VariantRegistered"));
     }
 
     #[test]
-    fn test_typename() {
-        assert_eq!(Data::<i32>::typename(), "Data<i32>");
-        assert_eq!(Data::<Data::<i64>>::typename(), "Data<Data<i64>>");
+    fn test_staticize_backtrace() {
+        let bt = std::backtrace::Backtrace::force_capture();
+        let s = staticize_backtrace(&bt);
+        assert_eq!(s, bt.to_string());
+        assert!(is_staticized(s));
+
+        let disabled = std::backtrace::Backtrace::disabled();
+        assert_eq!(staticize_backtrace(&disabled), disabled.to_string());
     }
 
+    #[cfg(feature = "ulid")]
     #[test]
-    fn tets_staticize_once() {
-        let called = atomic::AtomicI32::new(0);
+    fn test_staticize_ulid() {
+        let a = staticize_ulid();
+        let b = staticize_ulid();
+        assert_ne!(a, b);
+        assert!(is_subset_interned(&[a, b]));
+        assert_eq!(a.len(), 26);
+        assert!(a
+            .chars()
+            .all(|c| c.is_ascii_digit() || c.is_ascii_uppercase()));
+        assert!(a.parse::<ulid::Ulid>().is_ok());
+    }
 
-        let make_typename = || {
-            staticize_once!({
-                called.fetch_add(1, atomic::Ordering::SeqCst);
-                Data::<i32>::typename()
-            })
+    #[test]
+    fn test_staticize_header_row() {
+        assert_eq!(
+            staticize_header_row(&[("NAME", 8), ("AGE", 4), ("CITY", 6)]),
+            "NAME     AGE  CITY  "
+        );
+        assert_eq!(
+            staticize_header_row(&[("LONG HEADER", 4), ("X", 1)]),
+            "LONG HEADER X"
+        );
+        assert_eq!(staticize_header_row(&[("CAF\u{c9}", 5)]), "CAF\u{c9} ");
+        assert_eq!(staticize_header_row(&[]), "");
+    }
+
+    #[test]
+    fn test_evict_containing() {
+        let a = staticize("a/evict containing/1");
+        let b = staticize("evict containing/2");
+        let c = staticize("evict contain/3");
+        assert_eq!(evict_containing("evict containing/"), 2);
+        assert!(!is_staticized(a) && !is_staticized(b));
+        assert!(is_staticized(c));
+        assert_eq!(evict_containing("evict containing/"), 0);
+
+        assert_eq!(evict_containing(""), 0);
+        assert!(is_staticized(c));
+    }
+
+    #[test]
+    fn test_intern_proto_field() {
+        let s = intern_proto_field(&String::from("proto field"));
+        assert_eq!(s, "proto field");
+        assert_eq!(staticize("proto field").as_ptr(), s.as_ptr());
+    }
+
+    #[test]
+    fn test_staticize_normalize_newlines() {
+        assert_eq!(staticize_normalize_newlines("a\r\nb\rc\n"), "a\nb\nc\n");
+        assert_eq!(staticize_normalize_newlines("\r\r\n\n"), "\n\n\n");
+        let unix = staticize_normalize_newlines("newline\nstyle");
+        let windows = staticize_normalize_newlines("newline\r\nstyle");
+        assert_eq!(unix.as_ptr(), windows.as_ptr());
+    }
+
+    #[test]
+    fn test_staticize_unless_contains() {
+        assert_eq!(
+            staticize_unless_contains("unless contains ok", "<script>"),
+            Some("unless contains ok")
+        );
+        let s = "unless contains <script>";
+        assert_eq!(staticize_unless_contains(s, "<script>"), None);
+        assert!(!is_staticized(s));
+        assert_eq!(
+            staticize_unless_contains("unless contains anything", ""),
+            Some("unless contains anything")
+        );
+    }
+
+    #[test]
+    fn test_intern_type_names_for_tuple() {
+        let names = intern_type_names_for_tuple!(i32, Data<i64>, &str,);
+        assert_eq!(
+            names,
+            vec!["i32", std::any::type_name::<Data<i64>>(), "&str"]
+        );
+        assert!(is_subset_interned(&names));
+        let empty: Vec<&'static str> = intern_type_names_for_tuple!();
+        assert!(empty.is_empty());
+    }
+
+    #[cfg(feature = "yaml")]
+    #[test]
+    fn test_staticize_yaml_string_seq() {
+        let words = staticize_yaml_string_seq("- yaml apple\n- 'yaml: quoted'\n").unwrap();
+        assert_eq!(words, vec!["yaml apple", "yaml: quoted"]);
+        assert!(is_subset_interned(&words));
+        assert!(staticize_yaml_string_seq("[]").unwrap().is_empty());
+
+        assert!(staticize_yaml_string_seq("key: value").is_err());
+        assert!(staticize_yaml_string_seq("- yaml never staticized\n- [nested]").is_err());
+        assert!(!is_staticized("yaml never staticized"));
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn test_staticize_colored() {
+        assert_eq!(staticize_colored("ERROR", 31), "\x1b[31mERROR\x1b[0m");
+        assert_eq!(staticize_colored("INFO", 32), "\x1b[32mINFO\x1b[0m");
+        assert_eq!(staticize_colored("", 0), "\x1b[0m\x1b[0m");
+    }
+
+    #[test]
+    fn test_staticize_binary_repr() {
+        assert_eq!(staticize_binary_repr(b"AB"), "01000001 01000010");
+        assert_eq!(staticize_binary_repr(&[0, 255]), "00000000 11111111");
+        assert_eq!(staticize_binary_repr(&[]), "");
+    }
+
+    #[test]
+    fn test_intern_once_cell() {
+        let called = std::cell::Cell::new(0);
+        let cell = std::cell::OnceCell::new();
+        let make = || {
+            called.set(called.get() + 1);
+            String::from("once cell")
         };
 
-        let s1: &'static str = make_typename();
-        let s2: &'static str = make_typename();
-        let s3: &'static str = make_typename();
-        assert!(s1 == s2 && s2 == s3);
+        let s1 = intern_once_cell(&cell, make);
+        let s2 = intern_once_cell(&cell, make);
+        assert_eq!(s1, "once cell");
+        assert_eq!(s1.as_ptr(), s2.as_ptr());
+        assert!(is_staticized(s1));
+        assert_eq!(called.get(), 1);
+    }
 
-        // ensure the body been called only once
-        assert!(called.load(atomic::Ordering::SeqCst) == 1);
+    #[cfg(feature = "regex")]
+    #[test]
+    fn test_staticize_first_match() {
+        let re = regex::Regex::new(r"id=(\w+)").unwrap();
+        let s = staticize_first_match("GET /?id=abc&id=def", &re).unwrap();
+        assert_eq!(s, "id=abc");
+        assert!(is_staticized(s));
+        assert_eq!(staticize_first_match("GET /", &re), None);
+    }
+
+    #[test]
+    fn test_intern_full_env() {
+        std::env::set_var("STATIC_STR_OPS_TEST_FULL_ENV_A", "full env value");
+        std::env::set_var("STATIC_STR_OPS_TEST_FULL_ENV_B", "full env value");
+        let env = intern_full_env();
+        let a = env["STATIC_STR_OPS_TEST_FULL_ENV_A"];
+        let b = env["STATIC_STR_OPS_TEST_FULL_ENV_B"];
+        assert_eq!(a, "full env value");
+        assert_eq!(a.as_ptr(), b.as_ptr());
+        assert!(is_staticized("STATIC_STR_OPS_TEST_FULL_ENV_A"));
+    }
+
+    #[test]
+    fn test_staticize_bullet_list() {
+        assert_eq!(
+            staticize_bullet_list(&["item1", "item2", "item3"]),
+            "\u{2022} item1\n\u{2022} item2\n\u{2022} item3"
+        );
+        assert_eq!(staticize_bullet_list(&["only"]), "\u{2022} only");
+        assert_eq!(staticize_bullet_list(&[]), "");
+    }
+
+    #[test]
+    fn test_staticize_number_name() {
+        assert_eq!(staticize_number_name(0), "zero");
+        assert_eq!(staticize_number_name(7), "seven");
+        assert_eq!(staticize_number_name(13), "thirteen");
+        assert_eq!(staticize_number_name(40), "forty");
+        assert_eq!(staticize_number_name(99), "ninety-nine");
+        assert_eq!(staticize_number_name(100), "one hundred");
+        assert_eq!(staticize_number_name(101), "one hundred one");
+        assert_eq!(
+            staticize_number_name(123_456),
+            "one hundred twenty-three thousand four hundred fifty-six"
+        );
+        assert_eq!(
+            staticize_number_name(999_999),
+            "nine hundred ninety-nine thousand nine hundred ninety-nine"
+        );
+        assert_eq!(staticize_number_name(1_000_000), "one million");
+        assert_eq!(
+            staticize_number_name(u32::MAX),
+            "four billion two hundred ninety-four million nine hundred sixty-seven thousand \
+             two hundred ninety-five"
+        );
+    }
+
+    #[test]
+    fn test_staticize_unix_millis() {
+        assert_eq!(staticize_unix_millis(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            staticize_unix_millis(86_399_999),
+            "1970-01-01T23:59:59.999Z"
+        );
+        assert_eq!(
+            staticize_unix_millis(951_782_400_000),
+            "2000-02-29T00:00:00.000Z"
+        );
+        assert_eq!(
+            staticize_unix_millis(951_868_800_000),
+            "2000-03-01T00:00:00.000Z"
+        );
+        assert_eq!(
+            staticize_unix_millis(1_709_210_096_789),
+            "2024-02-29T12:34:56.789Z"
+        );
+        assert_eq!(
+            staticize_unix_millis(4_102_444_799_000),
+            "2099-12-31T23:59:59.000Z"
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn test_staticize_semver() {
+        assert_eq!(
+            staticize_semver(1, 2, 3, "rc.1", "git.abc123").unwrap(),
+            "1.2.3-rc.1+git.abc123"
+        );
+        assert_eq!(
+            staticize_semver(0, 1, 0, "alpha", "").unwrap(),
+            "0.1.0-alpha"
+        );
+        assert_eq!(
+            staticize_semver(2, 0, 0, "", "build.5").unwrap(),
+            "2.0.0+build.5"
+        );
+        assert_eq!(staticize_semver(1, 0, 0, "", "").unwrap(), "1.0.0");
+
+        assert!(staticize_semver(1, 0, 0, "rc..1", "").is_err());
+        assert!(staticize_semver(1, 0, 0, "", "build+meta").is_err());
     }
 }